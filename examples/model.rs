@@ -0,0 +1,41 @@
+//! Steps the pond without a window and prints a summary per seed.
+//!
+//! `cargo run --example model --no-default-features --features model -- <seconds> <seeds...>`
+
+use proa::{clock::TIMESTEP, pond::PondState, rng::DEFAULT_SEED};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let seconds: f32 = args.next().and_then(|s| s.parse().ok()).unwrap_or(60.0);
+    let mut seeds: Vec<u64> = args.filter_map(|s| s.parse().ok()).collect();
+    if seeds.is_empty() {
+        seeds.push(DEFAULT_SEED);
+    }
+
+    let ticks = (seconds / TIMESTEP).round() as u64;
+    for seed in seeds {
+        let mut pond = PondState::new(seed);
+        pond.drop_food(glam::Vec2::ZERO);
+
+        let start = std::time::Instant::now();
+        for _ in 0..ticks {
+            pond.step(TIMESTEP, &[]);
+        }
+        let elapsed = start.elapsed();
+
+        let boids = pond.boids().boids();
+        let mean_speed =
+            boids.iter().map(|boid| boid.velocity.length()).sum::<f32>() / boids.len() as f32;
+        let centroid = boids
+            .iter()
+            .map(|boid| boid.translation)
+            .sum::<glam::Vec2>()
+            / boids.len() as f32;
+        println!(
+            "seed {seed}: {} ticks in {elapsed:?}, {} fish, mean speed {mean_speed:.2}, centroid {centroid:.2}, {} food left",
+            pond.tick(),
+            boids.len(),
+            pond.food().len(),
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Packs many small images into one texture so sprites can share a draw.
+
+use glam::Vec2;
+use glow::HasContext;
+use image::RgbaImage;
+
+/// Transparent gap between packed images, keeps linear filtering from
+/// bleeding neighbours into each other.
+const PADDING: u32 = 1;
+
+/// A rectangle of an [`Atlas`], in uv space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasRegion {
+    pub texture: glow::Texture,
+    pub uv_min: Vec2,
+    pub uv_max: Vec2,
+    /// Size in pixels.
+    pub size: Vec2,
+}
+
+pub struct Atlas {
+    regions: Vec<AtlasRegion>,
+}
+
+impl Atlas {
+    /// A single white texel, for untextured sprites tinted by their color.
+    pub fn white(&self) -> AtlasRegion {
+        self.regions[0]
+    }
+}
+
+/// Shelf packer: images are placed left to right on rows as tall as the
+/// tallest image on them.
+pub struct AtlasBuilder {
+    size: u32,
+    pixels: RgbaImage,
+    cursor_x: u32,
+    cursor_y: u32,
+    shelf_height: u32,
+    placements: Vec<(u32, u32, u32, u32)>,
+}
+
+impl AtlasBuilder {
+    pub fn new(size: u32) -> Self {
+        let mut builder = Self {
+            size,
+            pixels: RgbaImage::new(size, size),
+            cursor_x: 0,
+            cursor_y: 0,
+            shelf_height: 0,
+            placements: Vec::new(),
+        };
+        builder
+            .add_image(&RgbaImage::from_pixel(1, 1, image::Rgba([255; 4])))
+            .unwrap();
+        builder
+    }
+
+    /// Returns the region index, or `None` if the atlas is full.
+    pub fn add_image(&mut self, image: &RgbaImage) -> Option<usize> {
+        let (width, height) = image.dimensions();
+        if self.cursor_x + width + PADDING > self.size {
+            self.cursor_x = 0;
+            self.cursor_y += self.shelf_height;
+            self.shelf_height = 0;
+        }
+        if self.cursor_x + width + PADDING > self.size
+            || self.cursor_y + height + PADDING > self.size
+        {
+            return None;
+        }
+
+        // flipped like `texture::load_texture` so that uv (0, 0) is the bottom left
+        let flipped = image::imageops::flip_vertical(image);
+        image::imageops::replace(
+            &mut self.pixels,
+            &flipped,
+            self.cursor_x as i64,
+            self.cursor_y as i64,
+        );
+        self.placements
+            .push((self.cursor_x, self.cursor_y, width, height));
+
+        self.cursor_x += width + PADDING;
+        self.shelf_height = self.shelf_height.max(height + PADDING);
+        Some(self.placements.len() - 1)
+    }
+
+    pub fn build(self, gl: &glow::Context) -> Atlas {
+        let size = self.size as f32;
+        let texture = unsafe {
+            let texture = gl.create_texture().unwrap();
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::RGBA as i32,
+                self.size as i32,
+                self.size as i32,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(Some(self.pixels.as_raw())),
+            );
+            gl.bind_texture(glow::TEXTURE_2D, None);
+            texture
+        };
+
+        let regions = self
+            .placements
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y, w, h))| {
+                let (uv_min, uv_max) = if i == 0 {
+                    // sample the middle of the white texel only
+                    let center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5) / size;
+                    (center, center)
+                } else {
+                    (
+                        Vec2::new(x as f32, y as f32) / size,
+                        Vec2::new((x + w) as f32, (y + h) as f32) / size,
+                    )
+                };
+                AtlasRegion {
+                    texture,
+                    uv_min,
+                    uv_max,
+                    size: Vec2::new(w as f32, h as f32),
+                }
+            })
+            .collect();
+
+        Atlas { regions }
+    }
+}
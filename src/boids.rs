@@ -1,15 +1,28 @@
-use crate::rng;
+use crate::{
+    debug::{self, DebugDraw},
+    pond::{Food, Obstacle},
+    rng::Rng,
+};
 use glam::Vec2;
 
-const BOUNDS: Vec2 = Vec2::new(720.0 / 4.0, 1280.0 / 4.0);
+pub const BOUNDS: Vec2 = Vec2::new(720.0 / 4.0, 1280.0 / 4.0);
 const BOID_COUNT: usize = 8;
 const MAX_SPEED: f32 = 150.0;
 const MAX_SPEED_SQ: f32 = MAX_SPEED * MAX_SPEED;
 const MIN_SPEED: f32 = 100.0;
 const MIN_SPEED_SQ: f32 = MIN_SPEED * MIN_SPEED;
 
+/// Boids shallower than this can reach food at the surface.
+pub const FEED_DEPTH: f32 = 0.15;
+/// Fraction of the remaining depth difference closed per second.
+const DIVE_RATE: f32 = 0.8;
+/// Mean seconds between a boid picking a new depth to swim at.
+const WANDER_INTERVAL: f32 = 8.0;
+const REST_PROBABILITY: f32 = 0.3;
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct BoidMemory {
-    boids: [Boid; BOID_COUNT],
+    boids: Vec<Boid>,
     //
     margin: Vec2,
     turn_factor: f32,
@@ -18,25 +31,36 @@ pub struct BoidMemory {
     cohesion_factor: f32,
     alignment_factor: f32,
     //
+    food_factor: f32,
+    obstacle_factor: f32,
+    //
     view_radius_squared: f32,
     separation_radius_squared: f32,
+    food_radius_squared: f32,
 }
 
 impl Default for BoidMemory {
     fn default() -> Self {
+        Self::new(&mut Rng::default())
+    }
+}
+
+impl BoidMemory {
+    pub fn new(rng: &mut Rng) -> Self {
+        let margin = BOUNDS / 4.0;
+        Self::with_boids(
+            (0..BOID_COUNT)
+                .map(|_| {
+                    let translation = rng.in_rect(BOUNDS - margin);
+                    Boid::spawn(rng, translation)
+                })
+                .collect(),
+        )
+    }
+
+    pub fn with_boids(boids: Vec<Boid>) -> Self {
         BoidMemory {
-            boids: core::array::from_fn(|i| Boid {
-                translation: Vec2::new(rng::sample_f32(i * 2), rng::sample_f32(i * 3 + 1))
-                    .normalize_or_zero()
-                    * 2.0
-                    * BOUNDS
-                    - BOUNDS,
-                velocity: Vec2::new(rng::sample_f32(i * 8), rng::sample_f32(i * 9 + 1))
-                    .normalize_or_zero()
-                    * 2.0
-                    * MAX_SPEED
-                    - MAX_SPEED,
-            }),
+            boids,
             //
             turn_factor: 1.0,
             margin: BOUNDS / 4.0,
@@ -45,27 +69,95 @@ impl Default for BoidMemory {
             cohesion_factor: 0.0005,
             alignment_factor: 0.01,
             //
+            food_factor: 0.02,
+            obstacle_factor: 4.0,
+            //
             view_radius_squared: 24f32.powi(2),
             separation_radius_squared: 12f32.powi(2),
+            food_radius_squared: 160f32.powi(2),
         }
     }
-}
 
-impl BoidMemory {
-    pub fn update(&mut self, dt: f32) {
+    pub fn update(&mut self, rng: &mut Rng, dt: f32, food: &[Food], obstacles: &[Obstacle]) {
         boid_forces(self);
+        seek_food(self, food);
+        avoid_obstacles(self, obstacles);
         avoid_bounds(self);
         apply_velocity(self, dt);
+        choose_depth(self, rng, dt, food);
+        apply_depth(self, dt);
     }
 
     pub fn boids(&self) -> &[Boid] {
         &self.boids
     }
+
+    pub fn spawn(&mut self, rng: &mut Rng, translation: Vec2) {
+        let translation = translation.clamp(-BOUNDS, BOUNDS);
+        self.boids.push(Boid::spawn(rng, translation));
+    }
+
+    /// Kick every boid within `radius` of `point` directly away from it.
+    pub fn startle(&mut self, point: Vec2, radius: f32) {
+        for boid in self.boids.iter_mut() {
+            let offset = boid.translation - point;
+            let distance = offset.length();
+            if distance < radius {
+                let strength = 1.0 - distance / radius;
+                boid.velocity +=
+                    offset.normalize_or(boid.velocity.normalize_or(Vec2::X)) * MAX_SPEED * strength;
+            }
+        }
+    }
+
+    /// Queues the bounds and margins, each boid's radii and velocity, and a
+    /// link between every pair of neighbors on `draw`.
+    pub fn draw_debug(&self, draw: &mut DebugDraw) {
+        draw.rect(-BOUNDS, BOUNDS, debug::WHITE);
+        draw.rect(-BOUNDS + self.margin, BOUNDS - self.margin, debug::GREY);
+
+        let view_radius = self.view_radius_squared.sqrt();
+        let separation_radius = self.separation_radius_squared.sqrt();
+        for (i, boid) in self.boids.iter().enumerate() {
+            draw.circle(boid.translation, view_radius, debug::BLUE);
+            draw.circle(boid.translation, separation_radius, debug::RED);
+            // a quarter second ahead
+            draw.arrow(
+                boid.translation,
+                boid.translation + boid.velocity * 0.25,
+                debug::GREEN,
+            );
+            for other in self.boids[i + 1..].iter() {
+                if boid.translation.distance_squared(other.translation) <= self.view_radius_squared
+                {
+                    draw.line(boid.translation, other.translation, debug::CYAN);
+                }
+            }
+        }
+    }
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Boid {
     pub translation: Vec2,
     pub velocity: Vec2,
+    /// 0 at the surface, 1 resting on the pond floor.
+    pub depth: f32,
+    /// Depth the boid is easing towards.
+    pub target_depth: f32,
+}
+
+impl Boid {
+    fn spawn(rng: &mut Rng, translation: Vec2) -> Self {
+        let velocity = rng.unit_vec2() * rng.range_f32(MIN_SPEED..MAX_SPEED);
+        let depth = rng.range_f32(0.2..0.7);
+        Self {
+            translation,
+            velocity,
+            depth,
+            target_depth: depth,
+        }
+    }
 }
 
 fn apply_velocity(memory: &mut BoidMemory, dt: f32) {
@@ -79,6 +171,61 @@ fn apply_velocity(memory: &mut BoidMemory, dt: f32) {
     }
 }
 
+/// Boids with food in sight rise to the surface, the rest now and then pick
+/// a new depth to cruise at, or sink to rest on the bottom.
+fn choose_depth(memory: &mut BoidMemory, rng: &mut Rng, dt: f32, food: &[Food]) {
+    for boid in memory.boids.iter_mut() {
+        let sees_food = food.iter().any(|food| {
+            food.translation.distance_squared(boid.translation) <= memory.food_radius_squared
+        });
+        if sees_food {
+            boid.target_depth = 0.0;
+        } else if boid.target_depth == 0.0 || rng.bool(dt / WANDER_INTERVAL) {
+            boid.target_depth = if rng.bool(REST_PROBABILITY) {
+                1.0
+            } else {
+                rng.range_f32(0.2..0.7)
+            };
+        }
+    }
+}
+
+fn apply_depth(memory: &mut BoidMemory, dt: f32) {
+    let t = 1.0 - (-DIVE_RATE * dt).exp();
+    for boid in memory.boids.iter_mut() {
+        boid.depth += (boid.target_depth - boid.depth) * t;
+    }
+}
+
+fn seek_food(memory: &mut BoidMemory, food: &[Food]) {
+    for boid in memory.boids.iter_mut() {
+        let nearest = food
+            .iter()
+            .map(|food| food.translation)
+            .filter(|t| t.distance_squared(boid.translation) <= memory.food_radius_squared)
+            .min_by(|a, b| {
+                a.distance_squared(boid.translation)
+                    .total_cmp(&b.distance_squared(boid.translation))
+            });
+        if let Some(translation) = nearest {
+            boid.velocity += (translation - boid.translation) * memory.food_factor;
+        }
+    }
+}
+
+fn avoid_obstacles(memory: &mut BoidMemory, obstacles: &[Obstacle]) {
+    for boid in memory.boids.iter_mut() {
+        for obstacle in obstacles.iter() {
+            let offset = boid.translation - obstacle.translation;
+            let reach = obstacle.radius + memory.margin.min_element();
+            if offset.length_squared() < reach * reach {
+                boid.velocity +=
+                    offset.normalize_or(Vec2::X) * memory.turn_factor * memory.obstacle_factor;
+            }
+        }
+    }
+}
+
 fn avoid_bounds(memory: &mut BoidMemory) {
     for boid in memory.boids.iter_mut() {
         if boid.translation.x < -BOUNDS.x + memory.margin.x {
@@ -97,7 +244,7 @@ fn avoid_bounds(memory: &mut BoidMemory) {
 }
 
 fn boid_forces(memory: &mut BoidMemory) {
-    let mut velocity_changes = [Vec2::ZERO; BOID_COUNT];
+    let mut velocity_changes = vec![Vec2::ZERO; memory.boids.len()];
 
     for (i, current_boid) in memory.boids.iter().enumerate() {
         let mut separation = Vec2::ZERO;
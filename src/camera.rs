@@ -0,0 +1,144 @@
+use crate::{
+    boids::BOUNDS,
+    debug::{self, DebugDraw},
+};
+use glam::{Mat4, Quat, Vec2, Vec3};
+
+/// World space area that [`Scaling::Fit`] and [`Scaling::Fill`] frame: the
+/// pond plus enough room for fins and tails at the edges.
+pub const VIEW_SIZE: Vec2 = Vec2::new(BOUNDS.x * 2.0 + 80.0, BOUNDS.y * 2.0 + 80.0);
+
+const MIN_ZOOM: f32 = 0.25;
+const MAX_ZOOM: f32 = 4.0;
+/// How quickly [`Camera2D::follow`] closes the gap, per second.
+const FOLLOW_RATE: f32 = 4.0;
+
+/// How world units map to window pixels before [`Camera2D::zoom`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Scaling {
+    /// All of [`VIEW_SIZE`] is visible, with bars of empty water on the
+    /// longer axis.
+    #[default]
+    Fit,
+    /// [`VIEW_SIZE`] covers the whole window, cropping the longer axis.
+    Fill,
+    /// A constant number of logical pixels per world unit, so a bigger
+    /// window shows more of the pond.
+    FixedPixelsPerUnit(f32),
+}
+
+impl Scaling {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Fit => Self::Fill,
+            Self::Fill => Self::FixedPixelsPerUnit(1.0),
+            Self::FixedPixelsPerUnit(_) => Self::Fit,
+        }
+    }
+}
+
+/// Orthographic camera shared by every world space renderer.
+///
+/// Screen coordinates are physical window pixels with the origin in the top
+/// left, as winit reports them.
+#[derive(Debug, Clone)]
+pub struct Camera2D {
+    pub position: Vec2,
+    pub zoom: f32,
+    /// Counter clockwise, in radians.
+    pub rotation: f32,
+    pub scaling: Scaling,
+    /// Physical pixels per logical pixel, from the window.
+    pub scale_factor: f32,
+    viewport: Vec2,
+}
+
+impl Camera2D {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            position: Vec2::ZERO,
+            zoom: 1.0,
+            rotation: 0.0,
+            scaling: Scaling::default(),
+            scale_factor: 1.0,
+            viewport: Vec2::new(width as f32, height as f32),
+        }
+    }
+
+    pub fn resize(&mut self, width: usize, height: usize) {
+        // a minimized window reports zero, keep the last usable size
+        if width > 0 && height > 0 {
+            self.viewport = Vec2::new(width as f32, height as f32);
+        }
+    }
+
+    /// Physical pixels per world unit at a zoom of 1.
+    pub fn pixels_per_unit(&self) -> f32 {
+        let ratio = self.viewport / VIEW_SIZE;
+        match self.scaling {
+            Scaling::Fit => ratio.min_element(),
+            Scaling::Fill => ratio.max_element(),
+            Scaling::FixedPixelsPerUnit(pixels) => pixels * self.scale_factor,
+        }
+    }
+
+    pub fn view_proj(&self) -> Mat4 {
+        let half = self.viewport / (2.0 * self.pixels_per_unit());
+        let proj = Mat4::orthographic_rh_gl(-half.x, half.x, -half.y, half.y, -1000.0, 1000.0);
+        let view = Mat4::from_scale(Vec3::new(self.zoom, self.zoom, 1.0))
+            * Mat4::from_quat(Quat::from_rotation_z(-self.rotation))
+            * Mat4::from_translation(-self.position.extend(0.0));
+        proj * view
+    }
+
+    pub fn screen_to_world(&self, screen: Vec2) -> Vec2 {
+        let centered = Vec2::new(
+            screen.x - self.viewport.x / 2.0,
+            self.viewport.y / 2.0 - screen.y,
+        );
+        let scale = self.pixels_per_unit() * self.zoom;
+        self.position + Vec2::from_angle(self.rotation).rotate(centered / scale)
+    }
+
+    /// Moves the camera so the world point under `from` ends up under `to`.
+    pub fn pan(&mut self, from: Vec2, to: Vec2) {
+        self.position += self.screen_to_world(from) - self.screen_to_world(to);
+        self.clamp_position();
+    }
+
+    /// Multiplies the zoom by `factor`, keeping the world point under
+    /// `screen` in place.
+    pub fn zoom_at(&mut self, screen: Vec2, factor: f32) {
+        let anchor = self.screen_to_world(screen);
+        self.zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        self.position += anchor - self.screen_to_world(screen);
+        self.clamp_position();
+    }
+
+    /// Eases towards `target`, framerate independent.
+    pub fn follow(&mut self, target: Vec2, dt: f32) {
+        let t = 1.0 - (-FOLLOW_RATE * dt).exp();
+        self.position = self.position.lerp(target, t);
+        self.clamp_position();
+    }
+
+    /// Queues the framed [`VIEW_SIZE`] and a cross on the center of the view
+    /// on `draw`.
+    pub fn draw_debug(&self, draw: &mut DebugDraw) {
+        draw.rect(-VIEW_SIZE / 2.0, VIEW_SIZE / 2.0, debug::BLUE);
+        // the same size on screen at any zoom
+        let arm = 8.0 / (self.pixels_per_unit() * self.zoom);
+        let right = Vec2::from_angle(self.rotation) * arm;
+        draw.line(self.position - right, self.position + right, debug::BLUE);
+        draw.line(
+            self.position - right.perp(),
+            self.position + right.perp(),
+            debug::BLUE,
+        );
+    }
+
+    /// Keeps the center of the view over the pond.
+    fn clamp_position(&mut self) {
+        self.position = self.position.clamp(-BOUNDS, BOUNDS);
+    }
+}
@@ -0,0 +1,116 @@
+use std::ops::Range;
+
+pub const TIMESTEP: f32 = 1.0 / 60.0;
+
+/// Upper bound on the ticks run for a single frame. A long stall (dragging the
+/// window, a breakpoint) would otherwise queue up seconds of simulation.
+pub const MAX_TICKS_PER_FRAME: u64 = 8;
+
+/// Turns variable frame deltas into a whole number of fixed simulation ticks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedClock {
+    timestep: f32,
+    accumulator: f32,
+    tick: u64,
+}
+
+impl Default for FixedClock {
+    fn default() -> Self {
+        Self::new(TIMESTEP)
+    }
+}
+
+impl FixedClock {
+    pub fn new(timestep: f32) -> Self {
+        Self {
+            timestep,
+            accumulator: 0.0,
+            tick: 0,
+        }
+    }
+
+    pub fn timestep(&self) -> f32 {
+        self.timestep
+    }
+
+    /// The next tick to be simulated.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Simulated seconds, derived from the tick count so it never drifts.
+    pub fn elapsed(&self) -> f32 {
+        self.tick as f32 * self.timestep
+    }
+
+    /// How far between the last and the next tick the frame is, in `[0, 1)`.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator / self.timestep
+    }
+
+    /// Accumulates `delta` seconds and returns the ticks that are now due.
+    pub fn advance(&mut self, delta: f32) -> Range<u64> {
+        self.accumulator += delta;
+        let mut ticks = (self.accumulator / self.timestep) as u64;
+        if ticks > MAX_TICKS_PER_FRAME {
+            ticks = MAX_TICKS_PER_FRAME;
+            self.accumulator = 0.0;
+        } else {
+            self.accumulator -= ticks as f32 * self.timestep;
+        }
+
+        let start = self.tick;
+        self.tick += ticks;
+        start..self.tick
+    }
+
+    /// Returns exactly `ticks` ticks, ignoring wall time. For captures,
+    /// where every frame must cover the same simulated time.
+    pub fn step(&mut self, ticks: u64) -> Range<u64> {
+        let start = self.tick;
+        self.tick += ticks;
+        start..self.tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a power of two, so the accumulator is exact
+    const STEP: f32 = 0.25;
+
+    #[test]
+    fn advance_carries_the_remainder() {
+        let mut clock = FixedClock::new(STEP);
+        assert_eq!(clock.advance(0.125), 0..0);
+        assert_eq!(clock.alpha(), 0.5);
+        assert_eq!(clock.advance(0.125), 0..1);
+        assert_eq!(clock.alpha(), 0.0);
+        assert_eq!(clock.advance(0.625), 1..3);
+        assert_eq!(clock.alpha(), 0.5);
+        assert_eq!(clock.advance(0.125), 3..4);
+        assert_eq!(clock.tick(), 4);
+        assert_eq!(clock.elapsed(), 1.0);
+    }
+
+    #[test]
+    fn advance_drops_long_stalls() {
+        let mut clock = FixedClock::new(STEP);
+        assert_eq!(clock.advance(100.0), 0..MAX_TICKS_PER_FRAME);
+        assert_eq!(clock.alpha(), 0.0);
+        assert_eq!(
+            clock.advance(STEP),
+            MAX_TICKS_PER_FRAME..MAX_TICKS_PER_FRAME + 1
+        );
+    }
+
+    #[test]
+    fn step_ignores_the_accumulator() {
+        let mut clock = FixedClock::new(STEP);
+        clock.advance(0.125);
+        assert_eq!(clock.step(3), 0..3);
+        assert_eq!(clock.alpha(), 0.5);
+        assert_eq!(clock.advance(0.125), 3..4);
+    }
+}
@@ -0,0 +1,71 @@
+//! Immediate mode debug lines and shapes in world space.
+//!
+//! Shapes are queued on a [`DebugDraw`]. The renderer owns the list and hands
+//! it out through `PondRenderer::debug_mut`, so any module can queue shapes
+//! on it during a frame. While the overlay is on the list is drawn on top of
+//! everything.
+
+use glam::{Vec2, Vec4};
+
+pub const WHITE: Vec4 = Vec4::ONE;
+pub const GREY: Vec4 = Vec4::new(0.6, 0.6, 0.6, 1.0);
+pub const RED: Vec4 = Vec4::new(1.0, 0.2, 0.2, 1.0);
+pub const GREEN: Vec4 = Vec4::new(0.2, 1.0, 0.2, 1.0);
+pub const BLUE: Vec4 = Vec4::new(0.3, 0.5, 1.0, 1.0);
+pub const YELLOW: Vec4 = Vec4::new(1.0, 0.9, 0.2, 1.0);
+pub const CYAN: Vec4 = Vec4::new(0.2, 0.9, 0.9, 1.0);
+
+/// Segments used for a full circle.
+const CIRCLE_SEGMENTS: usize = 24;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DebugLine {
+    pub from: Vec2,
+    pub to: Vec2,
+    pub color: Vec4,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DebugDraw {
+    lines: Vec<DebugLine>,
+}
+
+impl DebugDraw {
+    pub fn line(&mut self, from: Vec2, to: Vec2, color: Vec4) {
+        self.lines.push(DebugLine { from, to, color });
+    }
+
+    /// A line with a small head at `to`.
+    pub fn arrow(&mut self, from: Vec2, to: Vec2, color: Vec4) {
+        self.line(from, to, color);
+        let back = (from - to).normalize_or_zero() * 6.0;
+        self.line(to, to + Vec2::from_angle(0.5).rotate(back), color);
+        self.line(to, to + Vec2::from_angle(-0.5).rotate(back), color);
+    }
+
+    pub fn circle(&mut self, center: Vec2, radius: f32, color: Vec4) {
+        let point = |i: usize| {
+            let angle = std::f32::consts::TAU * i as f32 / CIRCLE_SEGMENTS as f32;
+            center + Vec2::from_angle(angle) * radius
+        };
+        for i in 0..CIRCLE_SEGMENTS {
+            self.line(point(i), point(i + 1), color);
+        }
+    }
+
+    /// Axis aligned, from corner `min` to corner `max`.
+    pub fn rect(&mut self, min: Vec2, max: Vec2, color: Vec4) {
+        let corners = [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+        for i in 0..corners.len() {
+            self.line(corners[i], corners[(i + 1) % corners.len()], color);
+        }
+    }
+
+    pub fn lines(&self) -> &[DebugLine] {
+        &self.lines
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
@@ -0,0 +1,237 @@
+//! Rendering without a window, for machines with no display or GPU.
+//!
+//! The GL context comes from EGL without any surface, everything is drawn
+//! into offscreen targets and read back. With Mesa the display comes from its
+//! surfaceless platform, which needs no display server, and llvmpipe renders
+//! on the CPU when there is no GPU.
+//!
+//! `cargo run --features headless -- --headless --frames 120 --every 30`
+
+use crate::{
+    camera::Camera2D,
+    clock::TIMESTEP,
+    pond::PondState,
+    renderer::PondRenderer,
+    rng::DEFAULT_SEED,
+    screenshot,
+    target::{RenderTarget, TargetFormat},
+};
+use glow::HasContext;
+use image::RgbaImage;
+use khronos_egl as egl;
+
+/// `EGL_PLATFORM_SURFACELESS_MESA` from `EGL_MESA_platform_surfaceless`.
+const PLATFORM_SURFACELESS_MESA: egl::Enum = 0x31DD;
+
+/// What to render and where to put it.
+#[derive(Debug, Clone)]
+pub struct HeadlessOptions {
+    pub width: usize,
+    pub height: usize,
+    pub seed: u64,
+    /// Frames to simulate, one tick each.
+    pub frames: usize,
+    /// Keep every n-th frame, the last frame is always kept.
+    pub every: usize,
+    /// Directory the frames are written to.
+    pub out: String,
+    /// With this off every post processing pass is skipped and the scene is
+    /// shown as drawn.
+    pub post_processing: bool,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            width: 1280,
+            height: 720,
+            seed: DEFAULT_SEED,
+            frames: 60,
+            every: 60,
+            out: String::from("headless"),
+            post_processing: true,
+        }
+    }
+}
+
+impl HeadlessOptions {
+    /// Parses `--size WxH`, `--seed`, `--frames`, `--every`, `--out` and
+    /// `--no-post`, ignoring `--headless` itself.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--headless" => continue,
+                "--no-post" => {
+                    options.post_processing = false;
+                    continue;
+                }
+                _ => {}
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {arg}"))?;
+            let number = |value: &str| {
+                value
+                    .parse::<usize>()
+                    .map_err(|err| format!("invalid {arg} `{value}`: {err}"))
+            };
+            match arg.as_str() {
+                "--size" => {
+                    let (width, height) = value
+                        .split_once('x')
+                        .ok_or_else(|| format!("expected --size WxH, got `{value}`"))?;
+                    options.width = number(width)?;
+                    options.height = number(height)?;
+                }
+                "--seed" => {
+                    options.seed = value
+                        .parse()
+                        .map_err(|err| format!("invalid --seed `{value}`: {err}"))?
+                }
+                "--frames" => options.frames = number(&value)?,
+                "--every" => options.every = number(&value)?,
+                "--out" => options.out = value,
+                _ => return Err(format!("unknown argument {arg}")),
+            }
+        }
+        if options.width == 0 || options.height == 0 || options.frames == 0 || options.every == 0 {
+            return Err(String::from("size, frames and every must be positive"));
+        }
+        Ok(options)
+    }
+}
+
+/// An OpenGL 3.3 core context with no surface, current on the thread that
+/// created it.
+pub struct HeadlessContext {
+    egl: egl::Instance<egl::Static>,
+    display: egl::Display,
+    context: egl::Context,
+    gl: glow::Context,
+}
+
+impl HeadlessContext {
+    pub fn new() -> Result<Self, egl::Error> {
+        let egl = egl::Instance::new(egl::Static);
+        // other drivers have no surfaceless platform but can still make a
+        // context on their default display
+        let display = unsafe {
+            egl.get_platform_display(
+                PLATFORM_SURFACELESS_MESA,
+                egl::DEFAULT_DISPLAY,
+                &[egl::ATTRIB_NONE],
+            )
+        }
+        .or_else(|_| {
+            unsafe { egl.get_display(egl::DEFAULT_DISPLAY) }.ok_or(egl::Error::BadDisplay)
+        })?;
+        egl.initialize(display)?;
+        egl.bind_api(egl::OPENGL_API)?;
+
+        // no surface is ever created, so any surface type will do instead of
+        // the default of window surfaces only
+        let attributes = [
+            egl::RENDERABLE_TYPE,
+            egl::OPENGL_BIT,
+            egl::SURFACE_TYPE,
+            0,
+            egl::NONE,
+        ];
+        let config = egl
+            .choose_first_config(display, &attributes)?
+            .ok_or(egl::Error::BadConfig)?;
+        let context = egl.create_context(
+            display,
+            config,
+            None,
+            &[
+                egl::CONTEXT_MAJOR_VERSION,
+                3,
+                egl::CONTEXT_MINOR_VERSION,
+                3,
+                egl::CONTEXT_OPENGL_PROFILE_MASK,
+                egl::CONTEXT_OPENGL_CORE_PROFILE_BIT,
+                egl::NONE,
+            ],
+        )?;
+        egl.make_current(display, None, None, Some(context))?;
+
+        let gl = unsafe {
+            glow::Context::from_loader_function(|name| {
+                egl.get_proc_address(name)
+                    .map_or(core::ptr::null(), |f| f as *const _)
+            })
+        };
+        Ok(Self {
+            egl,
+            display,
+            context,
+            gl,
+        })
+    }
+
+    pub fn gl(&self) -> &glow::Context {
+        &self.gl
+    }
+}
+
+impl Drop for HeadlessContext {
+    fn drop(&mut self) {
+        _ = self.egl.make_current(self.display, None, None, None);
+        _ = self.egl.destroy_context(self.display, self.context);
+        _ = self.egl.terminate(self.display);
+    }
+}
+
+/// Simulates a fresh pond from `options.seed` and renders it, returning the
+/// kept frames with their index.
+pub fn render_frames(gl: &glow::Context, options: &HeadlessOptions) -> Vec<(usize, RgbaImage)> {
+    let (width, height) = (options.width, options.height);
+    let mut state = PondState::new(options.seed);
+    let mut renderer = PondRenderer::new(gl, width, height);
+    if !options.post_processing {
+        for pass in renderer.passes_mut() {
+            pass.enabled = false;
+        }
+    }
+    let camera = Camera2D::new(width, height);
+    // there is no default framebuffer without a surface
+    let target = RenderTarget::new(gl, width, height, TargetFormat::Rgba8);
+
+    let mut frames = Vec::new();
+    for frame in 0..options.frames {
+        state.step(TIMESTEP, &[]);
+        // every frame is drawn so the cosmetic animations match a windowed run
+        target.bind(gl);
+        unsafe {
+            gl.viewport(0, 0, width as i32, height as i32);
+        }
+        renderer.render(gl, &state, &camera, TIMESTEP);
+        if (frame + 1) % options.every == 0 || frame + 1 == options.frames {
+            target.bind(gl);
+            frames.push((frame, screenshot::read_pixels(gl, width, height)));
+        }
+    }
+    target.delete(gl);
+    frames
+}
+
+/// Renders the frames in `options` and writes them to `options.out` as
+/// `frame-NNNN.png`, returning their paths.
+pub fn run(options: &HeadlessOptions) -> Result<Vec<String>, String> {
+    let context =
+        HeadlessContext::new().map_err(|err| format!("failed to create context: {err}"))?;
+    let renderer = unsafe { context.gl().get_parameter_string(glow::RENDERER) };
+    glazer::log!("[INFO] headless rendering on {renderer}");
+
+    std::fs::create_dir_all(&options.out).map_err(|err| err.to_string())?;
+    render_frames(context.gl(), options)
+        .into_iter()
+        .map(|(frame, image)| {
+            let path = format!("{}/frame-{frame:04}.png", options.out);
+            image.save(&path).map_err(|err| err.to_string())?;
+            Ok(path)
+        })
+        .collect()
+}
@@ -0,0 +1,392 @@
+use crate::{
+    shader::uniform,
+    spine::{Joint, Spine},
+};
+use glam::{Mat4, Quat, Vec2, Vec3, Vec4};
+use glow::HasContext;
+
+const ELLIPSE_SEGMENTS: usize = 20;
+/// World units of `z` between a fish at the surface and one on the floor,
+/// deeper fish are further from the camera.
+pub const DEPTH_RANGE: f32 = 100.0;
+
+/// Border drawn around every fish body and fin.
+#[derive(Debug, Clone, Copy)]
+pub struct Outline {
+    pub color: Vec4,
+    /// World units the outline extends past the fill, 0 disables it.
+    pub thickness: f32,
+}
+
+impl Default for Outline {
+    fn default() -> Self {
+        Self {
+            color: Vec4::ONE,
+            thickness: 2.0,
+        }
+    }
+}
+
+/// Draws every fish with a constant number of draw calls.
+///
+/// Fins are instances of a single static ellipse, each with its own model
+/// matrix, and all bodies are packed into one triangle list.
+///
+/// Outlines are the same shapes grown by [`Outline::thickness`] and drawn
+/// first, the fill then covers all but the border. Unlike line rasterization
+/// this looks the same on every driver and on WebGL.
+pub struct JointRenderer {
+    pub outline: Outline,
+    body_shader: glow::Program,
+    body_vao: glow::VertexArray,
+    body_vbo: glow::Buffer,
+    body_vbo_len: usize,
+    /// Every body fill, followed by every body outline.
+    body_vertices: Vec<Vec3>,
+    body_fill_len: usize,
+    outline_vertices: Vec<Vec3>,
+    //
+    fin_shader: glow::Program,
+    fin_vao: glow::VertexArray,
+    _fin_vbo: glow::Buffer,
+    instance_vbo: glow::Buffer,
+    instance_vbo_len: usize,
+    instances: Vec<Mat4>,
+    //
+    time: f32,
+}
+
+impl JointRenderer {
+    pub fn new(gl: &glow::Context) -> Self {
+        unsafe {
+            let body_shader =
+                crate::compile_shader!(gl, "shaders/joint.vert", "shaders/joint.frag");
+            let fin_shader = crate::compile_shader!(gl, "shaders/fin.vert", "shaders/joint.frag");
+
+            let body_vao = gl.create_vertex_array().unwrap();
+            let body_vbo = gl.create_buffer().unwrap();
+
+            gl.bind_vertex_array(Some(body_vao));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(body_vbo));
+
+            gl.vertex_attrib_pointer_f32(0, 3, glow::FLOAT, false, 3 * 4, 0);
+            gl.enable_vertex_attrib_array(0);
+
+            let xradius = 15.0;
+            let yradius = 25.0;
+            let ellipse: [Vec3; ELLIPSE_SEGMENTS] = core::array::from_fn(|i| {
+                let angle = 2.0 * std::f32::consts::PI * i as f32 / ELLIPSE_SEGMENTS as f32;
+                Vec3::new(xradius * angle.cos(), yradius * angle.sin(), 1.0)
+            });
+
+            let fin_vao = gl.create_vertex_array().unwrap();
+            let fin_vbo = gl.create_buffer().unwrap();
+            let instance_vbo = gl.create_buffer().unwrap();
+
+            gl.bind_vertex_array(Some(fin_vao));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(fin_vbo));
+            let data = core::slice::from_raw_parts(
+                ellipse.as_ptr() as *const u8,
+                ellipse.len() * core::mem::size_of::<Vec3>(),
+            );
+            gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, data, glow::STATIC_DRAW);
+            gl.vertex_attrib_pointer_f32(0, 3, glow::FLOAT, false, 3 * 4, 0);
+            gl.enable_vertex_attrib_array(0);
+
+            // a mat4 attribute takes up four consecutive vec4 locations
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(instance_vbo));
+            let stride = core::mem::size_of::<Mat4>() as i32;
+            for column in 0..4 {
+                let location = 1 + column;
+                gl.vertex_attrib_pointer_f32(
+                    location,
+                    4,
+                    glow::FLOAT,
+                    false,
+                    stride,
+                    column as i32 * 4 * 4,
+                );
+                gl.enable_vertex_attrib_array(location);
+                gl.vertex_attrib_divisor(location, 1);
+            }
+
+            gl.bind_vertex_array(None);
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+            Self {
+                outline: Outline::default(),
+                body_shader,
+                body_vao,
+                body_vbo,
+                body_vbo_len: 0,
+                body_vertices: Vec::new(),
+                body_fill_len: 0,
+                outline_vertices: Vec::new(),
+                fin_shader,
+                fin_vao,
+                _fin_vbo: fin_vbo,
+                instance_vbo,
+                instance_vbo_len: 0,
+                instances: Vec::new(),
+                time: 0.0,
+            }
+        }
+    }
+
+    /// `depths` holds the depth of every spine, from 0 at the surface to 1 on
+    /// the floor.
+    pub fn render(
+        &mut self,
+        gl: &glow::Context,
+        spines: &[Spine],
+        depths: &[f32],
+        view_proj: Mat4,
+        dt: f32,
+    ) {
+        self.time += dt;
+        self.prepare(gl, spines, view_proj, |i| {
+            Vec3::new(0.0, 0.0, -depths[i] * DEPTH_RANGE)
+        });
+
+        unsafe {
+            // fills cover their own outline but stay behind shallower fish
+            gl.depth_func(glow::LEQUAL);
+
+            gl.bind_vertex_array(Some(self.fin_vao));
+            if self.outline.thickness > 0.0 {
+                set_color(gl, self.fin_shader, self.outline.color);
+                uniform(gl, self.fin_shader, "outline", |location| {
+                    gl.uniform_1_f32(location, self.outline.thickness);
+                });
+                self.draw_fins(gl);
+                uniform(gl, self.fin_shader, "outline", |location| {
+                    gl.uniform_1_f32(location, 0.0);
+                });
+            }
+            set_color(gl, self.fin_shader, FILL);
+            self.draw_fins(gl);
+
+            gl.use_program(Some(self.body_shader));
+            gl.bind_vertex_array(Some(self.body_vao));
+            if self.outline.thickness > 0.0 {
+                set_color(gl, self.body_shader, self.outline.color);
+                self.draw_body_outlines(gl);
+            }
+            set_color(gl, self.body_shader, FILL);
+            self.draw_bodies(gl);
+
+            gl.bind_vertex_array(None);
+            gl.depth_func(glow::LESS);
+        }
+    }
+
+    /// Draws every fish as a flat white shape without outlines, the fish at
+    /// index `i` shifted by `offset(i)`. Used for shadows, so the shapes
+    /// always match what [`JointRenderer::render`] draws.
+    pub fn render_silhouettes(
+        &mut self,
+        gl: &glow::Context,
+        spines: &[Spine],
+        view_proj: Mat4,
+        offset: impl Fn(usize) -> Vec2,
+    ) {
+        self.prepare(gl, spines, view_proj, |i| offset(i).extend(0.0));
+
+        unsafe {
+            for shader in [self.body_shader, self.fin_shader] {
+                gl.use_program(Some(shader));
+                set_color(gl, shader, Vec4::ONE);
+            }
+
+            gl.bind_vertex_array(Some(self.fin_vao));
+            self.draw_fins(gl);
+
+            gl.use_program(Some(self.body_shader));
+            gl.bind_vertex_array(Some(self.body_vao));
+            self.draw_bodies(gl);
+
+            gl.bind_vertex_array(None);
+        }
+    }
+
+    /// Builds and uploads the geometry for `spines`, each moved by
+    /// `offset(i)`, and sets the uniforms, leaving the fin shader in use.
+    fn prepare(
+        &mut self,
+        gl: &glow::Context,
+        spines: &[Spine],
+        view_proj: Mat4,
+        offset: impl Fn(usize) -> Vec3,
+    ) {
+        self.instances.clear();
+        self.body_vertices.clear();
+        self.outline_vertices.clear();
+        for (i, spine) in spines.iter().enumerate() {
+            let offset = offset(i);
+            let joints = spine.joints.map(|joint| Joint {
+                translation: joint.translation + offset.truncate(),
+                ..joint
+            });
+            push_fins(&mut self.instances, &joints, offset.z);
+            push_body(&mut self.body_vertices, &joints, offset.z, 0.0);
+            push_body(
+                &mut self.outline_vertices,
+                &joints,
+                offset.z,
+                self.outline.thickness,
+            );
+        }
+        self.body_fill_len = self.body_vertices.len();
+        self.body_vertices.extend_from_slice(&self.outline_vertices);
+
+        unsafe {
+            upload(
+                gl,
+                self.instance_vbo,
+                &mut self.instance_vbo_len,
+                &self.instances,
+            );
+            upload(
+                gl,
+                self.body_vbo,
+                &mut self.body_vbo_len,
+                &self.body_vertices,
+            );
+
+            for shader in [self.body_shader, self.fin_shader] {
+                gl.use_program(Some(shader));
+                uniform(gl, shader, "proj_matrix", |location| {
+                    gl.uniform_matrix_4_f32_slice(location, false, &view_proj.to_cols_array());
+                });
+                uniform(gl, shader, "depth_range", |location| {
+                    gl.uniform_1_f32(location, DEPTH_RANGE);
+                });
+            }
+
+            gl.use_program(Some(self.fin_shader));
+            uniform(gl, self.fin_shader, "time", |location| {
+                gl.uniform_1_f32(location, self.time);
+            });
+            uniform(gl, self.fin_shader, "outline", |location| {
+                gl.uniform_1_f32(location, 0.0);
+            });
+        }
+    }
+
+    /// Needs the fin shader and vao bound.
+    fn draw_fins(&self, gl: &glow::Context) {
+        unsafe {
+            gl.draw_arrays_instanced(
+                glow::TRIANGLE_FAN,
+                0,
+                ELLIPSE_SEGMENTS as i32,
+                self.instances.len() as i32,
+            );
+        }
+    }
+
+    /// Needs the body shader and vao bound.
+    fn draw_bodies(&self, gl: &glow::Context) {
+        unsafe {
+            gl.draw_arrays(glow::TRIANGLES, 0, self.body_fill_len as i32);
+        }
+    }
+
+    /// Needs the body shader and vao bound.
+    fn draw_body_outlines(&self, gl: &glow::Context) {
+        unsafe {
+            gl.draw_arrays(
+                glow::TRIANGLES,
+                self.body_fill_len as i32,
+                (self.body_vertices.len() - self.body_fill_len) as i32,
+            );
+        }
+    }
+}
+
+const FILL: Vec4 = Vec4::new(1.0, 0.0, 0.0, 1.0);
+
+/// Needs `shader` in use.
+unsafe fn set_color(gl: &glow::Context, shader: glow::Program, color: Vec4) {
+    unsafe {
+        uniform(gl, shader, "color", |location| {
+            gl.uniform_4_f32(location, color.x, color.y, color.z, color.w);
+        });
+    }
+}
+
+/// Uploads `data` into a dynamic buffer, growing it when needed.
+unsafe fn upload<T>(gl: &glow::Context, buffer: glow::Buffer, len: &mut usize, data: &[T]) {
+    unsafe {
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(buffer));
+        let bytes =
+            core::slice::from_raw_parts(data.as_ptr() as *const u8, core::mem::size_of_val(data));
+        if *len < data.len() {
+            *len = data.len();
+            gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, bytes, glow::DYNAMIC_DRAW);
+        } else {
+            gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, bytes);
+        }
+        gl.bind_buffer(glow::ARRAY_BUFFER, None);
+    }
+}
+
+/// Fins sit just below the body at `z`.
+fn push_fins(instances: &mut Vec<Mat4>, joints: &[Joint], z: f32) {
+    let mut pectoral_fins = |seg: usize, size: f32| {
+        let joint = joints[seg];
+        let heading = (joints[seg - 1].translation - joint.translation).normalize_or_zero();
+        for side in [Vec2::Y, Vec2::NEG_Y].into_iter() {
+            instances.push(Mat4::from_scale_rotation_translation(
+                Vec3::ONE * size,
+                Quat::from_rotation_z(side.rotate(heading).to_angle() - 0.85 * side.y.signum()),
+                (joint.translation + side.rotate(heading) * 20.0).extend(z - 1.0),
+            ));
+        }
+    };
+    pectoral_fins(3, 0.8);
+    pectoral_fins(7, 0.95);
+
+    // caudal fin
+    let seg = joints.len() - 1;
+    let joint = joints[seg];
+    let heading = (joints[seg - 1].translation - joint.translation).normalize_or_zero();
+    instances.push(Mat4::from_scale_rotation_translation(
+        Vec3::new(0.3, 1.2, 1.0),
+        Quat::from_rotation_z(heading.to_angle() + std::f32::consts::PI / 2.0),
+        joint.translation.extend(z - 1.0),
+    ));
+}
+
+/// Appends the body outline, tail to head, as a triangle list so that
+/// several bodies can share one draw. `grow` widens the body and pushes the
+/// head and tail out by that many world units.
+fn push_body(vertices: &mut Vec<Vec3>, joints: &[Joint], z: f32, grow: f32) {
+    let mut strip = [Vec3::ZERO; 2 * crate::spine::SEGMENTS];
+    let mut last_heading = Vec2::X;
+    for (n, i) in (0..joints.len()).rev().enumerate() {
+        let joint = &joints[i];
+        let heading = if i > 0 {
+            let next = &joints[i - 1];
+            (next.translation - joint.translation).normalize_or(Vec2::X)
+        } else {
+            last_heading
+        };
+        let along = if i == 0 {
+            grow
+        } else if i == joints.len() - 1 {
+            -grow
+        } else {
+            0.0
+        };
+        let center = joint.translation + heading * along;
+        let size = joint.size + grow;
+        strip[n * 2] = (Vec2::Y.rotate(heading) * size + center).extend(z);
+        strip[n * 2 + 1] = (Vec2::NEG_Y.rotate(heading) * size + center).extend(z);
+        last_heading = heading;
+    }
+
+    for triangle in strip.windows(3) {
+        vertices.extend_from_slice(triangle);
+    }
+}
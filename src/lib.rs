@@ -8,42 +8,152 @@
 //! - Various fish sizes
 //! - Boid flocking
 
+#[cfg(feature = "render")]
 use crate::{
-    boids::BoidMemory,
-    pebbles::PebbleRenderer,
-    postprocess::PostProcessor,
-    shader::uniform,
-    sprite::{Sprite, SpriteRenderer},
+    camera::Camera2D,
+    clock::FixedClock,
+    pond::PondState,
+    recording::{Recording, RecordingFormat},
+    renderer::PondRenderer,
+    replay::{Input, Replay, ReplayLog},
+};
+#[cfg(feature = "render")]
+use glam::Vec2;
+#[cfg(feature = "render")]
+use glazer::winit::{
+    self,
+    event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent},
+    keyboard::{KeyCode, PhysicalKey},
 };
-use glam::{Mat4, Quat, Vec2, Vec3};
-use glazer::winit::{self, event::WindowEvent};
-use glow::HasContext;
 
-mod boids;
+#[cfg(feature = "model")]
+pub mod boids;
+#[cfg(feature = "model")]
+pub mod clock;
+#[cfg(feature = "model")]
+pub mod debug;
+#[cfg(feature = "model")]
+pub mod noise;
+#[cfg(feature = "model")]
+pub mod pond;
+#[cfg(feature = "model")]
+pub mod replay;
+#[cfg(feature = "model")]
+pub mod rng;
+#[cfg(feature = "model")]
+pub mod spine;
+
+#[cfg(feature = "headless")]
+pub mod headless;
+
+#[cfg(feature = "render")]
+mod atlas;
+#[cfg(feature = "render")]
+mod camera;
+#[cfg(feature = "render")]
+mod joint;
+#[cfg(feature = "render")]
+mod overlay;
+#[cfg(feature = "render")]
 mod pebbles;
+#[cfg(feature = "render")]
 mod postprocess;
-mod rng;
+#[cfg(feature = "render")]
+mod recording;
+#[cfg(feature = "render")]
+mod renderer;
+#[cfg(feature = "render")]
+mod ripple;
+#[cfg(feature = "render")]
+mod screenshot;
+#[cfg(feature = "render")]
 mod shader;
+#[cfg(feature = "render")]
+mod shadow;
+#[cfg(feature = "render")]
 mod sprite;
+#[cfg(feature = "render")]
+mod target;
+#[cfg(feature = "render")]
 mod texture;
+#[cfg(feature = "render")]
+mod wake;
+
+#[cfg(feature = "render")]
+const REPLAY_PATH: &str = "replay.txt";
+#[cfg(feature = "render")]
+const STATE_PATH: &str = "state.txt";
 
+#[cfg(feature = "render")]
 #[derive(Default)]
 pub struct Memory {
     world: Option<World>,
+    /// Outside of [`World`], the window can report it before the first frame.
+    scale_factor: Option<f32>,
 }
 
-const SEGMENTS: usize = 14;
+#[cfg(feature = "render")]
 struct World {
-    cursor: Vec2,
-    joints: Vec<[Joint; SEGMENTS]>,
-    joint_renderer: JointRenderer,
-    sprites: [Sprite; SEGMENTS],
-    sprite_renderer: SpriteRenderer,
-    pebble_renderer: PebbleRenderer,
-    boid_memory: BoidMemory,
-    postprocessor: PostProcessor,
+    camera: Camera2D,
+    /// Index of the fish the camera eases towards.
+    following: Option<usize>,
+    /// Last cursor position in window pixels.
+    screen_cursor: Vec2,
+    panning: bool,
+    shift: bool,
+    /// Resolution multiple of a screenshot to take after the next frame.
+    screenshot: Option<usize>,
+    recording: Option<Recording>,
+    /// Draws the [`debug`] shapes over the pond, toggled with F3.
+    debug_overlay: bool,
+    clock: FixedClock,
+    replay: Replay,
+    inputs: Vec<Input>,
+    state: PondState,
+    renderer: PondRenderer,
+}
+
+#[cfg(feature = "render")]
+impl World {
+    /// Restart the simulation from `seed`, everything that affects a tick
+    /// must be reset here for replays to line up.
+    fn reset(&mut self, seed: u64) {
+        self.state = PondState::new(seed);
+        self.clock = FixedClock::default();
+        self.inputs.clear();
+        self.following = None;
+    }
+
+    /// Follow the fish closest to the cursor, or stop following.
+    fn toggle_follow(&mut self) {
+        if self.following.take().is_some() {
+            return;
+        }
+        let cursor = self.camera.screen_to_world(self.screen_cursor);
+        self.following = self
+            .state
+            .spines()
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                let a = a.head().distance_squared(cursor);
+                let b = b.head().distance_squared(cursor);
+                a.total_cmp(&b)
+            })
+            .map(|(i, _)| i);
+    }
 }
 
+/// Seed from `PROA_SEED`, so a session can be recreated from the log line.
+#[cfg(feature = "render")]
+fn initial_seed() -> u64 {
+    std::env::var("PROA_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(rng::DEFAULT_SEED)
+}
+
+#[cfg(feature = "render")]
 #[unsafe(no_mangle)]
 pub fn handle_input(
     glazer::PlatformInput {
@@ -54,33 +164,292 @@ pub fn handle_input(
         WindowEvent::KeyboardInput {
             event:
                 winit::event::KeyEvent {
-                    physical_key:
-                        winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape),
+                    physical_key: PhysicalKey::Code(KeyCode::Escape),
                     ..
                 },
             ..
         } => {
             std::process::exit(0);
         }
+        WindowEvent::KeyboardInput {
+            event:
+                winit::event::KeyEvent {
+                    physical_key: PhysicalKey::Code(code),
+                    state: ElementState::Pressed,
+                    repeat: false,
+                    ..
+                },
+            ..
+        } => {
+            if let Some(world) = &mut memory.world {
+                match code {
+                    // toggle recording
+                    KeyCode::F5 => match std::mem::take(&mut world.replay) {
+                        Replay::Recording(log) => save_replay(&log),
+                        _ => {
+                            let seed = world.state.seed();
+                            world.reset(seed);
+                            world.replay =
+                                Replay::Recording(ReplayLog::new(seed, world.clock.timestep()));
+                            glazer::log!("[INFO] recording replay with seed {seed}");
+                        }
+                    },
+                    KeyCode::F6 => match std::fs::write(STATE_PATH, world.state.serialize()) {
+                        Ok(()) => glazer::log!("[INFO] saved state to {STATE_PATH}"),
+                        Err(err) => glazer::log!("[ERROR] failed to save state: {err}"),
+                    },
+                    KeyCode::F7 => match std::fs::read_to_string(STATE_PATH)
+                        .map_err(|err| err.to_string())
+                        .and_then(|src| PondState::deserialize(&src))
+                    {
+                        Ok(state) => {
+                            // the loaded state breaks the recording, keep what it has so far
+                            match std::mem::take(&mut world.replay) {
+                                Replay::Live => {}
+                                Replay::Recording(log) => {
+                                    glazer::log!("[INFO] stopped recording to load state");
+                                    save_replay(&log);
+                                }
+                                Replay::Playing { .. } => {
+                                    glazer::log!("[INFO] stopped replay to load state")
+                                }
+                            }
+                            let diff = world.state.diff(&state);
+                            for line in diff.iter().take(16) {
+                                glazer::log!("[INFO] {line}");
+                            }
+                            if diff.len() > 16 {
+                                glazer::log!("[INFO] ... {} more differences", diff.len() - 16);
+                            }
+                            world.state = state;
+                        }
+                        Err(err) => glazer::log!("[ERROR] failed to load state: {err}"),
+                    },
+                    KeyCode::KeyF => world.toggle_follow(),
+                    // swing the sun around the pond
+                    KeyCode::BracketLeft | KeyCode::BracketRight => {
+                        let angle = if code == KeyCode::BracketLeft {
+                            -0.25
+                        } else {
+                            0.25
+                        };
+                        let sun = world.renderer.sun_mut();
+                        let rotation = glam::Quat::from_rotation_z(angle);
+                        sun.direction = rotation * sun.direction;
+                    }
+                    // toggle post processing passes by their position in the chain
+                    KeyCode::Digit1
+                    | KeyCode::Digit2
+                    | KeyCode::Digit3
+                    | KeyCode::Digit4
+                    | KeyCode::Digit5
+                    | KeyCode::Digit6
+                    | KeyCode::Digit7 => {
+                        let index = [
+                            KeyCode::Digit1,
+                            KeyCode::Digit2,
+                            KeyCode::Digit3,
+                            KeyCode::Digit4,
+                            KeyCode::Digit5,
+                            KeyCode::Digit6,
+                            KeyCode::Digit7,
+                        ]
+                        .iter()
+                        .position(|key| *key == code)
+                        .unwrap();
+                        if let Some(pass) = world.renderer.passes_mut().get_mut(index) {
+                            pass.enabled = !pass.enabled;
+                            glazer::log!(
+                                "[INFO] {} pass {}",
+                                pass.kind.name(),
+                                if pass.enabled { "on" } else { "off" }
+                            );
+                        }
+                    }
+                    KeyCode::KeyO => {
+                        let outline = world.renderer.outline_mut();
+                        outline.thickness = match outline.thickness {
+                            t if t <= 0.0 => 1.0,
+                            t if t < 4.0 => t * 2.0,
+                            _ => 0.0,
+                        };
+                        glazer::log!("[INFO] outline thickness {}", outline.thickness);
+                    }
+                    KeyCode::KeyT => {
+                        if let Some(tonemap) = world.renderer.tonemap_mut() {
+                            tonemap.tonemapper = tonemap.tonemapper.next();
+                            glazer::log!("[INFO] tonemapper {:?}", tonemap.tonemapper);
+                        }
+                    }
+                    KeyCode::Minus | KeyCode::Equal => {
+                        if let Some(tonemap) = world.renderer.tonemap_mut() {
+                            tonemap.exposure += if code == KeyCode::Minus { -0.25 } else { 0.25 };
+                            glazer::log!("[INFO] exposure {:+.2} ev", tonemap.exposure);
+                        }
+                    }
+                    KeyCode::F3 => {
+                        world.debug_overlay = !world.debug_overlay;
+                        glazer::log!(
+                            "[INFO] debug overlay {}",
+                            if world.debug_overlay { "on" } else { "off" }
+                        );
+                    }
+                    KeyCode::F2 => {
+                        world.camera.scaling = world.camera.scaling.next();
+                        glazer::log!("[INFO] scaling {:?}", world.camera.scaling);
+                    }
+                    // shift for a high resolution still
+                    KeyCode::F12 => {
+                        world.screenshot = Some(if world.shift {
+                            screenshot::HIRES_SCALE
+                        } else {
+                            1
+                        });
+                    }
+                    // start or stop a clip, shift for a gif
+                    KeyCode::F10 => match world.recording.take() {
+                        Some(recording) => finish_recording(recording),
+                        None => {
+                            let format = if world.shift {
+                                RecordingFormat::Gif
+                            } else {
+                                RecordingFormat::Png
+                            };
+                            let seconds = Recording::seconds_from_env();
+                            match Recording::start(format, seconds, world.clock.timestep()) {
+                                Ok(recording) => {
+                                    glazer::log!(
+                                        "[INFO] recording {seconds}s to {}",
+                                        recording.path()
+                                    );
+                                    world.recording = Some(recording);
+                                }
+                                Err(err) => {
+                                    glazer::log!("[ERROR] failed to start recording: {err}")
+                                }
+                            }
+                        }
+                    },
+                    KeyCode::F9 => match ReplayLog::load(REPLAY_PATH) {
+                        Ok(log) => play(world, log),
+                        Err(err) => glazer::log!("[ERROR] failed to load replay: {err}"),
+                    },
+                    _ => {}
+                }
+            }
+        }
         WindowEvent::CursorMoved { position, .. } => {
             if let Some(world) = &mut memory.world {
-                world.cursor = Vec2::new(position.x as f32, position.y as f32);
+                let screen = Vec2::new(position.x as f32, position.y as f32);
+                if world.panning {
+                    world.camera.pan(world.screen_cursor, screen);
+                }
+                world.screen_cursor = screen;
+                let cursor = world.camera.screen_to_world(screen);
+                world.inputs.push(Input::Cursor(cursor));
+            }
+        }
+        WindowEvent::MouseWheel { delta, .. } => {
+            if let Some(world) = &mut memory.world {
+                let lines = match delta {
+                    MouseScrollDelta::LineDelta(_, y) => y,
+                    MouseScrollDelta::PixelDelta(p) => p.y as f32 / 40.0,
+                };
+                world
+                    .camera
+                    .zoom_at(world.screen_cursor, 1.1f32.powf(lines));
             }
         }
-        WindowEvent::Resized(size) => {
+        WindowEvent::MouseInput {
+            state,
+            button: MouseButton::Middle,
+            ..
+        } => {
             if let Some(world) = &mut memory.world {
-                let w = size.width as usize;
-                let h = size.height as usize;
-                // NOTE: Fish would look to small if they were resized
-                // world.joint_renderer.resize(gl, w, h);
-                // world.sprite_renderer.resize(gl, w, h);
-                world.postprocessor.resize(gl, w, h);
+                world.panning = state == ElementState::Pressed;
+                if world.panning {
+                    world.following = None;
+                }
+            }
+        }
+        WindowEvent::MouseInput {
+            state: ElementState::Pressed,
+            button,
+            ..
+        } => {
+            if let Some(world) = &mut memory.world {
+                // inputs carry the position of the last cursor input so they
+                // replay without needing the cursor events in between
+                let cursor = world
+                    .inputs
+                    .iter()
+                    .rev()
+                    .find_map(|input| match input {
+                        Input::Cursor(p) => Some(*p),
+                        _ => None,
+                    })
+                    .unwrap_or(world.state.cursor());
+                match button {
+                    MouseButton::Left => world.inputs.push(Input::Click(cursor)),
+                    // shift to drop food instead of a fish
+                    MouseButton::Right if world.shift => world.inputs.push(Input::Food(cursor)),
+                    MouseButton::Right => world.inputs.push(Input::Spawn(cursor)),
+                    _ => {}
+                }
+            }
+        }
+        WindowEvent::ModifiersChanged(modifiers) => {
+            if let Some(world) = &mut memory.world {
+                world.shift = modifiers.state().shift_key();
+            }
+        }
+        WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+            memory.scale_factor = Some(scale_factor as f32);
+            if let Some(world) = &mut memory.world {
+                world.camera.scale_factor = scale_factor as f32;
+            }
+        }
+        WindowEvent::Resized(size) if size.width > 0 && size.height > 0 => {
+            if let Some(world) = &mut memory.world {
+                world
+                    .renderer
+                    .resize(gl, size.width as usize, size.height as usize);
             }
         }
         _ => {}
     }
 }
 
+#[cfg(feature = "render")]
+fn save_replay(log: &ReplayLog) {
+    match log.save(REPLAY_PATH) {
+        Ok(()) => glazer::log!("[INFO] saved replay to {REPLAY_PATH}"),
+        Err(err) => glazer::log!("[ERROR] failed to save replay: {err}"),
+    }
+}
+
+#[cfg(feature = "render")]
+fn play(world: &mut World, log: ReplayLog) {
+    glazer::log!(
+        "[INFO] playing replay with seed {} ({} inputs)",
+        log.seed,
+        log.events.len()
+    );
+    world.reset(log.seed);
+    world.clock = FixedClock::new(log.timestep);
+    world.replay = Replay::Playing { log, next: 0 };
+}
+
+#[cfg(feature = "render")]
+fn finish_recording(recording: Recording) {
+    glazer::log!(
+        "[INFO] recorded {} frames to {}",
+        recording.frames_written(),
+        recording.path()
+    );
+}
+
+#[cfg(feature = "render")]
 #[unsafe(no_mangle)]
 pub fn update_and_render(
     glazer::PlatformUpdate {
@@ -92,319 +461,109 @@ pub fn update_and_render(
         ..
     }: glazer::PlatformUpdate<Memory>,
 ) {
-    let speed = 350.0;
-    let separation = 20.0;
-    let min_joint_angle = std::f32::consts::PI / 2.0;
-
-    let joint_sizes = [
-        10.0, 18.0, 25.0, 23.0, 24.0, 23.0, 22.0, 21.0, 16.0, 14.0, 10.0, 6.0, 3.0, 2.0,
-    ];
-
-    let world = memory.world.get_or_insert_with(|| World {
-        cursor: Vec2::ZERO,
-        joints: Vec::new(),
-        joint_renderer: JointRenderer::new(gl, width, height),
-        sprites: [Sprite::from_size(gl, Vec2::splat(16.0)); SEGMENTS],
-        sprite_renderer: SpriteRenderer::new(gl, width, height),
-        pebble_renderer: PebbleRenderer::new(gl),
-        boid_memory: BoidMemory::default(),
-        postprocessor: PostProcessor::new(gl, width, height),
-    });
-
-    world.boid_memory.update(delta);
-    let num_boids = world.boid_memory.boids().len();
-    if world.joints.len() < num_boids {
-        while world.joints.len() < num_boids {
-            let mut offset = 0.0;
-            world.joints.push(joint_sizes.map(|size| {
-                let translation = Vec2::X * offset;
-                offset += separation;
-                Joint { size, translation }
-            }))
-        }
-    }
-
-    for (boid, joints) in world
-        .boid_memory
-        .boids()
-        .iter()
-        .zip(world.joints.iter_mut())
-    {
-        let head_translation = boid.translation;
-        let mut target =
-            head_translation + boid.velocity.normalize_or(Vec2::X) * separation + speed * delta;
-
-        for i in 0..joints.len() {
-            let joint = &mut joints[i];
-            let offset = target - joint.translation;
-            let current_distance = offset.length();
-
-            if current_distance > 0.0 {
-                let constrained_position = target - offset.normalize() * separation;
-                joint.translation = constrained_position;
-            }
-
-            target = joint.translation;
-            if i < joints.len() - 2 {
-                let joint_translation = joints[i].translation;
-                let anchor_translation = joints[i + 1].translation;
-                let joint2_translation = joints[i + 2].translation;
-
-                let normalized_joint =
-                    (joint_translation - anchor_translation).normalize_or(Vec2::X);
-                let normalized_joint2 =
-                    (joint2_translation - anchor_translation).normalize_or(Vec2::X);
-                let angle = normalized_joint.angle_to(normalized_joint2);
-
-                if angle.abs() < min_joint_angle {
-                    let rotation = min_joint_angle * angle.signum();
-                    let constrained_direction = normalized_joint.rotate(Vec2::from_angle(rotation));
-                    let joint2 = &mut joints[i + 2];
-                    joint2.translation = constrained_direction * separation + anchor_translation;
-                }
+    let world = memory.world.get_or_insert_with(|| {
+        let seed = initial_seed();
+        glazer::log!("[INFO] seed {seed}");
+        let mut camera = Camera2D::new(width, height);
+        camera.scale_factor = memory.scale_factor.unwrap_or(1.0);
+        let mut world = World {
+            camera,
+            following: None,
+            screen_cursor: Vec2::ZERO,
+            panning: false,
+            shift: false,
+            screenshot: None,
+            recording: None,
+            debug_overlay: false,
+            clock: FixedClock::default(),
+            replay: Replay::Live,
+            inputs: Vec::new(),
+            state: PondState::new(seed),
+            renderer: PondRenderer::new(gl, width, height),
+        };
+        if let Ok(path) = std::env::var("PROA_REPLAY") {
+            match ReplayLog::load(&path) {
+                Ok(log) => play(&mut world, log),
+                Err(err) => glazer::log!("[ERROR] failed to load replay: {err}"),
             }
         }
-
-        for (sprite, joint) in world.sprites.iter_mut().zip(joints.iter()) {
-            sprite.translation = joint.translation.extend(10.0);
-        }
-    }
-
-    unsafe {
-        world.postprocessor.bind_framebuffer(gl);
-        gl.clear_color(0.0, 0.1, 0.0, 1.0);
-        gl.clear(glow::COLOR_BUFFER_BIT | glow::DEPTH_BUFFER_BIT);
-        gl.enable(glow::DEPTH_TEST);
-        gl.enable(glow::BLEND);
-        gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
-
-        world.pebble_renderer.render(gl);
-
-        for joints in world.joints.iter() {
-            let vertex_count = world.joint_renderer.bind_ellipse(gl);
-            let mut render_pectoral_fins = |seg: usize, size: f32| {
-                let joint = joints[seg];
-                let heading = (joints[seg - 1].translation - joint.translation).normalize_or_zero();
-                for side in [Vec2::Y, Vec2::NEG_Y].into_iter() {
-                    let transform = Mat4::from_scale_rotation_translation(
-                        Vec3::ONE * size,
-                        Quat::from_rotation_z(
-                            side.rotate(heading).to_angle() - 0.85 * side.y.signum(),
-                        ),
-                        (joint.translation + side.rotate(heading) * 20.0).extend(-1.0),
-                    );
-                    world
-                        .joint_renderer
-                        .render(gl, transform, vertex_count, glow::TRIANGLE_FAN);
-                }
-            };
-            render_pectoral_fins(3, 0.8);
-            render_pectoral_fins(7, 0.95);
-
-            // caudal fin
-            let seg = joints.len() - 1;
-            let joint = joints[seg];
-            let heading = (joints[seg - 1].translation - joint.translation).normalize_or_zero();
-            let transform = Mat4::from_scale_rotation_translation(
-                Vec3::new(0.3, 1.2, 1.0),
-                Quat::from_rotation_z(heading.to_angle() + std::f32::consts::PI / 2.0),
-                joint.translation.extend(-1.0),
-            );
-            world
-                .joint_renderer
-                .render(gl, transform, vertex_count, glow::TRIANGLE_FAN);
-
-            let vertex_count = world.joint_renderer.bind_joints(gl, joints, delta);
-            world
-                .joint_renderer
-                .render(gl, Mat4::IDENTITY, vertex_count, glow::TRIANGLE_STRIP);
-        }
-
-        // debug spine
-        // for sprite in world.sprites.iter() {
-        //     world.sprite_renderer.render(gl, sprite);
-        // }
-
-        // post processing
-        gl.bind_framebuffer(glow::FRAMEBUFFER, None);
-        // gl.clear_color(0.1, 0.1, 0.1, 1.0);
-        // gl.clear(glow::COLOR_BUFFER_BIT | glow::DEPTH_BUFFER_BIT);
-        gl.disable(glow::DEPTH_TEST);
-        world.postprocessor.render_to_active_framebuffer(gl, delta);
-    }
-}
-
-#[derive(Clone, Copy)]
-struct Joint {
-    size: f32,
-    translation: Vec2,
-}
-
-pub struct JointRenderer {
-    shader: glow::Program,
-    vao: glow::VertexArray,
-    vbo: glow::Buffer,
-    vbo_len: usize,
-    time: f32,
-}
-
-impl JointRenderer {
-    pub fn new(gl: &glow::Context, width: usize, height: usize) -> Self {
-        unsafe {
-            let shader = crate::compile_shader!(gl, "shaders/joint.vert", "shaders/joint.frag");
-            gl.use_program(Some(shader));
-
-            uniform(gl, shader, "proj_matrix", |location| {
-                let w_2 = width as f32 / 2.0;
-                let h_2 = height as f32 / 2.0;
-                let proj_matrix = Mat4::orthographic_rh_gl(-w_2, w_2, -h_2, h_2, -1000.0, 1000.0);
-                gl.uniform_matrix_4_f32_slice(location, false, &proj_matrix.to_cols_array());
-            });
-
-            let vao = gl.create_vertex_array().unwrap();
-            let vbo = gl.create_buffer().unwrap();
-
-            gl.bind_vertex_array(Some(vao));
-            gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
-
-            gl.vertex_attrib_pointer_f32(0, 3, glow::FLOAT, false, 3 * 4, 0);
-            gl.enable_vertex_attrib_array(0);
-
-            gl.bind_vertex_array(None);
-            gl.bind_buffer(glow::ARRAY_BUFFER, None);
-
-            Self {
-                shader,
-                vao,
-                vbo,
-                vbo_len: 0,
-                time: 0.0,
-            }
+        world
+    });
+    world.camera.resize(width, height);
+
+    let ticks = match &world.recording {
+        Some(recording) => world.clock.step(recording.ticks_per_frame()),
+        None => world.clock.advance(delta),
+    };
+    let dt = world.clock.timestep();
+    // rendering only sees time in whole ticks, so replays look identical too
+    let frame_delta = (ticks.end - ticks.start) as f32 * dt;
+    for tick in ticks {
+        let inputs = world.replay.inputs(tick, &mut world.inputs);
+        world.state.step(dt, &inputs);
+        if world.replay.is_finished() {
+            glazer::log!("[INFO] replay finished");
+            world.replay = Replay::Live;
         }
     }
 
-    pub fn resize(&self, gl: &glow::Context, width: usize, height: usize) {
-        unsafe {
-            gl.use_program(Some(self.shader));
-            uniform(gl, self.shader, "proj_matrix", |location| {
-                let w_2 = width as f32 / 2.0;
-                let h_2 = height as f32 / 2.0;
-                let proj_matrix = Mat4::orthographic_rh_gl(-w_2, w_2, -h_2, h_2, -1000.0, 1000.0);
-                gl.uniform_matrix_4_f32_slice(location, false, &proj_matrix.to_cols_array());
-            });
-        }
+    // a recording plays back at simulated speed, so the camera has to keep
+    // the same pace
+    let camera_delta = if world.recording.is_some() {
+        frame_delta
+    } else {
+        delta
+    };
+    if let Some(spine) = world.following.and_then(|i| world.state.spines().get(i)) {
+        world.camera.follow(spine.head(), camera_delta);
     }
 
-    fn bind_joints(&mut self, gl: &glow::Context, joints: &[Joint], dt: f32) -> usize {
-        unsafe {
-            self.time += dt;
-
-            gl.use_program(Some(self.shader));
-            uniform(gl, self.shader, "ripple", |location| {
-                gl.uniform_1_f32(location, 0.0);
-            });
-
-            let mut vertices = Vec::with_capacity(joints.len() * 2);
-            let mut last_heading = Vec2::X;
-            for i in (0..joints.len()).rev() {
-                if i > 0 {
-                    let joint = &joints[i];
-                    let next = &joints[i - 1];
-                    let heading = (next.translation - joint.translation).normalize_or(Vec2::X);
-                    let vert = Vec2::Y.rotate(heading) * joint.size + joint.translation;
-                    vertices.push(vert.extend(0.0));
-                    let vert = Vec2::NEG_Y.rotate(heading) * joint.size + joint.translation;
-                    vertices.push(vert.extend(0.0));
-                    last_heading = heading;
-                } else {
-                    let joint = &joints[i];
-                    let vert = Vec2::Y.rotate(last_heading) * joint.size + joint.translation;
-                    vertices.push(vert.extend(0.0));
-                    let vert = Vec2::NEG_Y.rotate(last_heading) * joint.size + joint.translation;
-                    vertices.push(vert.extend(0.0));
-                }
-            }
-
-            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vbo));
-            let data = core::slice::from_raw_parts(
-                vertices.as_ptr() as *const u8,
-                vertices.len() * core::mem::size_of::<Vec3>(),
-            );
-            if self.vbo_len <= vertices.len() {
-                self.vbo_len = vertices.len();
-                gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, data, glow::DYNAMIC_DRAW);
-            } else {
-                gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, data);
-            }
-
-            vertices.len()
-        }
+    world
+        .renderer
+        .render(gl, &world.state, &world.camera, frame_delta);
+    if world.debug_overlay {
+        world.camera.draw_debug(world.renderer.debug_mut());
+        world.renderer.render_debug(gl, &world.state, &world.camera);
     }
 
-    fn bind_ellipse(&mut self, gl: &glow::Context) -> usize {
-        unsafe {
-            gl.use_program(Some(self.shader));
-            uniform(gl, self.shader, "ripple", |location| {
-                gl.uniform_1_f32(location, 1.0);
-            });
-            uniform(gl, self.shader, "time", |location| {
-                gl.uniform_1_f32(location, self.time);
-            });
-
-            let xradius = 15.0;
-            let yradius = 25.0;
-
-            let segments = 20;
-            let mut vertices = Vec::with_capacity(segments * 2);
-            for i in 0..segments {
-                let angle = 2.0 * std::f32::consts::PI * i as f32 / segments as f32;
-                vertices.push(Vec3::new(xradius * angle.cos(), yradius * angle.sin(), 1.0));
-            }
-
-            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vbo));
-            let data = core::slice::from_raw_parts(
-                vertices.as_ptr() as *const u8,
-                vertices.len() * core::mem::size_of::<Vec3>(),
-            );
-            if self.vbo_len <= vertices.len() {
-                self.vbo_len = vertices.len();
-                gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, data, glow::DYNAMIC_DRAW);
-            } else {
-                gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, data);
-            }
-
-            vertices.len()
+    if let Some(scale) = world.screenshot.take() {
+        // the frame was just drawn, so the animations stay where they are
+        let image = world.renderer.render_image(
+            gl,
+            &world.state,
+            &world.camera,
+            (width, height),
+            scale,
+            0.0,
+        );
+        match image {
+            Some(image) => match screenshot::save(&image) {
+                Ok(path) => glazer::log!(
+                    "[INFO] saved {}x{} screenshot to {path}",
+                    image.width(),
+                    image.height()
+                ),
+                Err(err) => glazer::log!("[ERROR] failed to save screenshot: {err}"),
+            },
+            None => glazer::log!("[INFO] window is minimized, skipped screenshot"),
         }
     }
 
-    fn render(&mut self, gl: &glow::Context, model_matrix: Mat4, vertices: usize, mode: u32) {
-        unsafe {
-            gl.use_program(Some(self.shader));
-            gl.bind_vertex_array(Some(self.vao));
-
-            uniform(gl, self.shader, "model_matrix", |location| {
-                gl.uniform_matrix_4_f32_slice(location, false, &model_matrix.to_cols_array());
-            });
-
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                // outlines
-                gl.line_width(4.0);
-                uniform(gl, self.shader, "color", |location| {
-                    gl.uniform_4_f32(location, 1.0, 1.0, 1.0, 1.0);
-                });
-                gl.polygon_mode(glow::FRONT_AND_BACK, glow::LINE);
-                gl.draw_arrays(mode, 0, vertices as i32);
+    // a minimized window has no frame to record, the recording picks up
+    // again once it is restored
+    if let Some(recording) = &mut world.recording
+        && let Some(image) =
+            world
+                .renderer
+                .render_image(gl, &world.state, &world.camera, (width, height), 1, 0.0)
+    {
+        match recording.push(image) {
+            Ok(false) => {}
+            Ok(true) => finish_recording(world.recording.take().unwrap()),
+            Err(err) => {
+                glazer::log!("[ERROR] failed to record frame: {err}");
+                world.recording = None;
             }
-
-            // fill
-            gl.disable(glow::DEPTH_TEST);
-            uniform(gl, self.shader, "color", |location| {
-                gl.uniform_4_f32(location, 1.0, 0.0, 0.0, 1.0);
-            });
-            gl.polygon_mode(glow::FRONT_AND_BACK, glow::FILL);
-            gl.draw_arrays(mode, 0, vertices as i32);
-            gl.enable(glow::DEPTH_TEST);
         }
     }
 }
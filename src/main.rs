@@ -1,4 +1,22 @@
 fn main() {
+    #[cfg(feature = "headless")]
+    if std::env::args().any(|arg| arg == "--headless") {
+        let result = proa::headless::HeadlessOptions::from_args(std::env::args().skip(1))
+            .and_then(|options| proa::headless::run(&options));
+        match result {
+            Ok(paths) => {
+                for path in paths {
+                    println!("{path}");
+                }
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     glazer::run(
         proa::Memory::default(),
         1280,
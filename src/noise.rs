@@ -0,0 +1,209 @@
+//! CPU ports of `shaders/noise.glsl`.
+//!
+//! Every function follows the GLSL source operation for operation so that
+//! gameplay can sample the same fields the water shows. The hashes are built
+//! on `sin`, so results agree with the GPU to within its `sin` precision.
+
+use glam::{Vec2, Vec3, Vec4};
+
+fn fract(x: f32) -> f32 {
+    x - x.floor()
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+pub fn random2(st: Vec2) -> Vec2 {
+    let st = Vec2::new(
+        st.dot(Vec2::new(127.1, 311.7)),
+        st.dot(Vec2::new(269.5, 183.3)),
+    );
+    -1.0 + 2.0 * Vec2::new(fract(st.x.sin() * 43758.547), fract(st.y.sin() * 43758.547))
+}
+
+pub fn random3(st: Vec3) -> Vec3 {
+    let st = Vec3::new(
+        st.dot(Vec3::new(127.1, 311.7, 74.7)),
+        st.dot(Vec3::new(269.5, 183.3, 246.1)),
+        st.dot(Vec3::new(113.5, 271.9, 124.6)),
+    );
+    -1.0 + 2.0
+        * Vec3::new(
+            fract(st.x.sin() * 43758.547),
+            fract(st.y.sin() * 43758.547),
+            fract(st.z.sin() * 43758.547),
+        )
+}
+
+/// 2D gradient noise in roughly `[-0.7, 0.7]`.
+pub fn perlin(st: Vec2) -> f32 {
+    let i = st.floor();
+    let f = st - i;
+    let u = f * f * (3.0 - 2.0 * f);
+    let corner = |offset: Vec2| random2(i + offset).dot(f - offset);
+    lerp(
+        lerp(
+            corner(Vec2::new(0.0, 0.0)),
+            corner(Vec2::new(1.0, 0.0)),
+            u.x,
+        ),
+        lerp(
+            corner(Vec2::new(0.0, 1.0)),
+            corner(Vec2::new(1.0, 1.0)),
+            u.x,
+        ),
+        u.y,
+    )
+}
+
+/// 3D gradient noise, use `z` as time for a smoothly evolving 2D field.
+pub fn perlin3(st: Vec3) -> f32 {
+    let i = st.floor();
+    let f = st - i;
+    let u = f * f * (3.0 - 2.0 * f);
+    let corner = |offset: Vec3| random3(i + offset).dot(f - offset);
+    let face = |z: f32| {
+        lerp(
+            lerp(
+                corner(Vec3::new(0.0, 0.0, z)),
+                corner(Vec3::new(1.0, 0.0, z)),
+                u.x,
+            ),
+            lerp(
+                corner(Vec3::new(0.0, 1.0, z)),
+                corner(Vec3::new(1.0, 1.0, z)),
+                u.x,
+            ),
+            u.y,
+        )
+    };
+    lerp(face(0.0), face(1.0), u.z)
+}
+
+// https://weber.itn.liu.se/~stegu/simplexnoise/simplexnoise.pdf
+pub fn simplex(st: Vec2) -> f32 {
+    const F2: f32 = 0.366_025_4;
+    const G2: f32 = 0.211_324_87;
+
+    let i = (st + st.dot(Vec2::splat(F2))).floor();
+    let x0 = st - i + i.dot(Vec2::splat(G2));
+    let i1 = if x0.x > x0.y {
+        Vec2::new(1.0, 0.0)
+    } else {
+        Vec2::new(0.0, 1.0)
+    };
+    let x1 = x0 - i1 + G2;
+    let x2 = x0 - 1.0 + 2.0 * G2;
+
+    let t = (0.5 - Vec3::new(x0.dot(x0), x1.dot(x1), x2.dot(x2))).max(Vec3::ZERO);
+    let t = t * t * t * t;
+    let n = Vec3::new(
+        random2(i).dot(x0),
+        random2(i + i1).dot(x1),
+        random2(i + 1.0).dot(x2),
+    );
+    70.0 * t.dot(n)
+}
+
+pub fn simplex3(st: Vec3) -> f32 {
+    const F3: f32 = 1.0 / 3.0;
+    const G3: f32 = 1.0 / 6.0;
+
+    let i = (st + st.dot(Vec3::splat(F3))).floor();
+    let x0 = st - i + i.dot(Vec3::splat(G3));
+
+    let step = |edge: f32, x: f32| if x < edge { 0.0 } else { 1.0 };
+    let g = Vec3::new(step(x0.y, x0.x), step(x0.z, x0.y), step(x0.x, x0.z));
+    let l = 1.0 - g;
+    let i1 = g.min(Vec3::new(l.z, l.x, l.y));
+    let i2 = g.max(Vec3::new(l.z, l.x, l.y));
+
+    let x1 = x0 - i1 + G3;
+    let x2 = x0 - i2 + 2.0 * G3;
+    let x3 = x0 - 1.0 + 3.0 * G3;
+
+    let t = (0.6 - Vec4::new(x0.dot(x0), x1.dot(x1), x2.dot(x2), x3.dot(x3))).max(Vec4::ZERO);
+    let t = t * t * t * t;
+    let n = Vec4::new(
+        random3(i).dot(x0),
+        random3(i + i1).dot(x1),
+        random3(i + i2).dot(x2),
+        random3(i + 1.0).dot(x3),
+    );
+    32.0 * t.dot(n)
+}
+
+/// Fractal brownian motion over [`perlin`], each octave doubles the frequency
+/// and halves the amplitude.
+pub fn fbm(mut st: Vec2, octaves: u32) -> f32 {
+    let mut value = 0.0;
+    let mut amplitude = 0.5;
+    for _ in 0..octaves {
+        value += amplitude * perlin(st);
+        st *= 2.0;
+        amplitude *= 0.5;
+    }
+    value
+}
+
+/// Fractal brownian motion over [`perlin3`].
+pub fn fbm3(mut st: Vec3, octaves: u32) -> f32 {
+    let mut value = 0.0;
+    let mut amplitude = 0.5;
+    for _ in 0..octaves {
+        value += amplitude * perlin3(st);
+        st *= 2.0;
+        amplitude *= 0.5;
+    }
+    value
+}
+
+/// The water surface height field, `st` is in uv space.
+pub fn multisampled_perlin(st: Vec2) -> f32 {
+    let mut height = perlin(st * 10.0) * 0.5;
+    height += perlin(st * 20.0) * 0.3;
+    height += perlin(st * 30.0) * 0.2;
+    height
+}
+
+/// Central difference gradient of `f` at `st`, for flow fields that follow
+/// the noise downhill.
+pub fn gradient(f: impl Fn(Vec2) -> f32, st: Vec2, epsilon: f32) -> Vec2 {
+    let dx = f(st + Vec2::X * epsilon) - f(st - Vec2::X * epsilon);
+    let dy = f(st + Vec2::Y * epsilon) - f(st - Vec2::Y * epsilon);
+    Vec2::new(dx, dy) / (2.0 * epsilon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `perlin` as evaluated by `shaders/noise.glsl` on Mesa's llvmpipe. The
+    /// hashes amplify any difference in `sin` by more than 40000, so the CPU
+    /// only agrees to a few thousandths.
+    const SHADER_PERLIN: [(Vec2, f32); 4] = [
+        (Vec2::new(0.5, 0.5), -0.27783),
+        (Vec2::new(1.25, 3.75), 0.11248),
+        (Vec2::new(-2.3, 0.7), 0.28681),
+        (Vec2::new(10.1, 4.2), 0.01592),
+    ];
+
+    #[test]
+    fn perlin_matches_shader() {
+        for (st, expected) in SHADER_PERLIN {
+            let value = perlin(st);
+            assert!(
+                (value - expected).abs() < 2e-3,
+                "perlin({st}) = {value}, the shader gives {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn perlin_is_zero_on_lattice() {
+        for st in [Vec2::ZERO, Vec2::new(3.0, -7.0), Vec2::new(-12.0, 40.0)] {
+            assert_eq!(perlin(st), 0.0);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{debug::DebugDraw, shader::uniform};
+use glam::{Mat4, Vec2};
+use glow::HasContext;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct LineVertex {
+    position: [f32; 2],
+    color: [f32; 4],
+}
+
+/// Draws the lines queued on a [`DebugDraw`] as one pixel wide GL lines,
+/// which every driver and WebGL support.
+pub struct DebugOverlay {
+    shader: glow::Program,
+    vao: glow::VertexArray,
+    vbo: glow::Buffer,
+    vbo_len: usize,
+    vertices: Vec<LineVertex>,
+}
+
+impl DebugOverlay {
+    pub fn new(gl: &glow::Context) -> Self {
+        unsafe {
+            let shader = crate::compile_shader!(gl, "shaders/debug.vert", "shaders/debug.frag");
+
+            let vao = gl.create_vertex_array().unwrap();
+            let vbo = gl.create_buffer().unwrap();
+            gl.bind_vertex_array(Some(vao));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
+            let stride = core::mem::size_of::<LineVertex>() as i32;
+            gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, stride, 0);
+            gl.enable_vertex_attrib_array(0);
+            gl.vertex_attrib_pointer_f32(1, 4, glow::FLOAT, false, stride, 2 * 4);
+            gl.enable_vertex_attrib_array(1);
+            gl.bind_vertex_array(None);
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+            Self {
+                shader,
+                vao,
+                vbo,
+                vbo_len: 0,
+                vertices: Vec::new(),
+            }
+        }
+    }
+
+    /// Draws and clears the lines queued on `draw` into the bound
+    /// framebuffer.
+    pub fn render(&mut self, gl: &glow::Context, draw: &mut DebugDraw, view_proj: Mat4) {
+        self.vertices.clear();
+        self.vertices.extend(draw.lines().iter().flat_map(|line| {
+            let vertex = |position: Vec2| LineVertex {
+                position: position.into(),
+                color: line.color.into(),
+            };
+            [vertex(line.from), vertex(line.to)]
+        }));
+        draw.clear();
+        if self.vertices.is_empty() {
+            return;
+        }
+
+        unsafe {
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vbo));
+            let data = core::slice::from_raw_parts(
+                self.vertices.as_ptr() as *const u8,
+                core::mem::size_of_val(self.vertices.as_slice()),
+            );
+            if self.vbo_len < self.vertices.len() {
+                self.vbo_len = self.vertices.len();
+                gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, data, glow::DYNAMIC_DRAW);
+            } else {
+                gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, data);
+            }
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+            gl.use_program(Some(self.shader));
+            uniform(gl, self.shader, "proj_matrix", |location| {
+                gl.uniform_matrix_4_f32_slice(location, false, &view_proj.to_cols_array());
+            });
+
+            gl.disable(glow::DEPTH_TEST);
+            gl.enable(glow::BLEND);
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+            gl.bind_vertex_array(Some(self.vao));
+            gl.draw_arrays(glow::LINES, 0, self.vertices.len() as i32);
+            gl.bind_vertex_array(None);
+        }
+    }
+}
@@ -1,11 +1,17 @@
-use crate::{compile_shader, postprocess, texture};
+use crate::{
+    camera::VIEW_SIZE, compile_shader, postprocess, postprocess::Sun, shader::uniform, texture,
+};
+use glam::{Mat4, Vec3};
 use glow::HasContext;
 
+/// The pond floor: a blurred pebble texture with animated caustics on top.
 pub struct PebbleRenderer {
     pebbles: glow::Texture,
     shader: glow::Program,
+    caustics_shader: glow::Program,
     vao: glow::VertexArray,
     _vbo: glow::Buffer,
+    time: f32,
 }
 
 impl PebbleRenderer {
@@ -14,17 +20,68 @@ impl PebbleRenderer {
         Self {
             pebbles: texture::load_texture(gl, "assets/pebbles.jpg"),
             shader: compile_shader!(gl, "shaders/postprocess.vert", "shaders/pebbles.frag"),
+            caustics_shader: compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/caustics.frag",
+                "shaders/noise.glsl",
+                "shaders/surface.glsl"
+            ),
             vao,
             _vbo: vbo,
+            time: 0.0,
         }
     }
 
-    pub fn render(&self, gl: &glow::Context) {
+    /// `sun` should be the light the water is drawn with, so highlights and
+    /// caustics agree. `floor_depth` is the world units between the surface
+    /// and the floor, the caustics are projected through that much water.
+    pub fn render(
+        &mut self,
+        gl: &glow::Context,
+        view_proj: Mat4,
+        sun: &Sun,
+        floor_depth: f32,
+        dt: f32,
+    ) {
+        self.time += dt;
+        let inv_view_proj = view_proj.inverse();
         unsafe {
             gl.use_program(Some(self.shader));
+            uniform(gl, self.shader, "inv_view_proj", |location| {
+                gl.uniform_matrix_4_f32_slice(location, false, &inv_view_proj.to_cols_array());
+            });
+            uniform(gl, self.shader, "floor_size", |location| {
+                gl.uniform_2_f32(location, VIEW_SIZE.x, VIEW_SIZE.y);
+            });
             gl.bind_vertex_array(Some(self.vao));
             gl.bind_texture(glow::TEXTURE_2D, Some(self.pebbles));
             gl.draw_arrays(glow::TRIANGLES, 0, 6);
+
+            gl.use_program(Some(self.caustics_shader));
+            uniform(gl, self.caustics_shader, "inv_view_proj", |location| {
+                gl.uniform_matrix_4_f32_slice(location, false, &inv_view_proj.to_cols_array());
+            });
+            uniform(gl, self.caustics_shader, "time", |location| {
+                gl.uniform_1_f32(location, self.time);
+            });
+            uniform(gl, self.caustics_shader, "sun_direction", |location| {
+                let d = sun.direction.normalize_or(Vec3::Z);
+                gl.uniform_3_f32(location, d.x, d.y, d.z);
+            });
+            uniform(gl, self.caustics_shader, "sun_color", |location| {
+                let c = sun.color;
+                gl.uniform_3_f32(location, c.x, c.y, c.z);
+            });
+            uniform(gl, self.caustics_shader, "floor_depth", |location| {
+                gl.uniform_1_f32(location, floor_depth);
+            });
+            // light only ever adds to the floor underneath it
+            gl.disable(glow::DEPTH_TEST);
+            gl.blend_func(glow::ONE, glow::ONE);
+            gl.draw_arrays(glow::TRIANGLES, 0, 6);
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+            gl.enable(glow::DEPTH_TEST);
         }
     }
 }
@@ -0,0 +1,550 @@
+//! The pond simulation, free of any GL or platform code.
+//!
+//! Everything a tick reads or writes lives in [`PondState`], so two states
+//! created from the same seed and stepped with the same inputs stay identical.
+
+use crate::{
+    boids::{self, Boid, BoidMemory},
+    clock::MAX_TICKS_PER_FRAME,
+    debug::{self, DebugDraw},
+    replay::{Input, parse_bits},
+    rng::Rng,
+    spine::{Joint, SEGMENTS, Spine},
+};
+use glam::Vec2;
+use std::fmt::Write;
+
+const HEADER: &str = "proa-state 4";
+
+const FOOD_AMOUNT: f32 = 1.0;
+const EAT_RADIUS: f32 = 20.0;
+/// Food eaten per second by a single fish.
+const EAT_RATE: f32 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Food {
+    /// Unique within a [`PondState`], counting up in the order food is
+    /// dropped.
+    pub id: u64,
+    pub translation: Vec2,
+    pub amount: f32,
+}
+
+/// A click on the surface, kept for [`MAX_TICKS_PER_FRAME`] ticks so that a
+/// frame sees every click of the ticks it ran.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Click {
+    /// The tick the click was applied on.
+    pub tick: u64,
+    pub translation: Vec2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obstacle {
+    pub translation: Vec2,
+    pub radius: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PondState {
+    seed: u64,
+    tick: u64,
+    rng: Rng,
+    cursor: Vec2,
+    boids: BoidMemory,
+    spines: Vec<Spine>,
+    food: Vec<Food>,
+    next_food_id: u64,
+    clicks: Vec<Click>,
+    obstacles: Vec<Obstacle>,
+}
+
+impl PondState {
+    pub fn new(seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        let boids = BoidMemory::new(&mut rng);
+        let spines = boids
+            .boids()
+            .iter()
+            .map(|boid| Spine::new(boid.translation, boid.velocity))
+            .collect();
+
+        Self {
+            seed,
+            tick: 0,
+            rng,
+            cursor: Vec2::ZERO,
+            boids,
+            spines,
+            food: Vec::new(),
+            next_food_id: 0,
+            clicks: Vec::new(),
+            obstacles: Vec::new(),
+        }
+    }
+
+    pub fn step(&mut self, dt: f32, inputs: &[Input]) {
+        let tick = self.tick;
+        self.clicks
+            .retain(|click| tick.saturating_sub(click.tick) < MAX_TICKS_PER_FRAME);
+        for input in inputs.iter() {
+            match *input {
+                Input::Cursor(p) => self.cursor = p,
+                Input::Click(p) => {
+                    self.boids.startle(p, 120.0);
+                    self.clicks.push(Click {
+                        tick,
+                        translation: p,
+                    });
+                }
+                Input::Spawn(p) => self.spawn(p),
+                Input::Food(p) => self.drop_food(p),
+            }
+        }
+
+        self.boids
+            .update(&mut self.rng, dt, &self.food, &self.obstacles);
+
+        for food in self.food.iter_mut() {
+            for boid in self.boids.boids().iter() {
+                let near =
+                    boid.translation.distance_squared(food.translation) < EAT_RADIUS * EAT_RADIUS;
+                if near && boid.depth < boids::FEED_DEPTH {
+                    food.amount -= EAT_RATE * dt;
+                }
+            }
+        }
+        self.food.retain(|food| food.amount > 0.0);
+
+        for (boid, spine) in self.boids.boids().iter().zip(self.spines.iter_mut()) {
+            spine.follow(boid.translation, boid.velocity, dt);
+        }
+
+        self.tick += 1;
+    }
+
+    pub fn spawn(&mut self, translation: Vec2) {
+        self.boids.spawn(&mut self.rng, translation);
+        let boid = self.boids.boids().last().unwrap();
+        self.spines
+            .push(Spine::new(boid.translation, boid.velocity));
+    }
+
+    pub fn drop_food(&mut self, translation: Vec2) {
+        self.food.push(Food {
+            id: self.next_food_id,
+            translation: translation.clamp(-boids::BOUNDS, boids::BOUNDS),
+            amount: FOOD_AMOUNT,
+        });
+        self.next_food_id += 1;
+    }
+
+    pub fn add_obstacle(&mut self, translation: Vec2, radius: f32) {
+        self.obstacles.push(Obstacle {
+            translation,
+            radius,
+        });
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Number of ticks stepped since [`PondState::new`].
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn cursor(&self) -> Vec2 {
+        self.cursor
+    }
+
+    pub fn boids(&self) -> &BoidMemory {
+        &self.boids
+    }
+
+    /// One spine per boid, in the same order as [`BoidMemory::boids`].
+    pub fn spines(&self) -> &[Spine] {
+        &self.spines
+    }
+
+    pub fn food(&self) -> &[Food] {
+        &self.food
+    }
+
+    /// Clicks of the last few ticks, oldest first.
+    pub fn clicks(&self) -> &[Click] {
+        &self.clicks
+    }
+
+    pub fn obstacles(&self) -> &[Obstacle] {
+        &self.obstacles
+    }
+
+    /// Queues every spine's joints and the flock on `draw`.
+    pub fn draw_debug(&self, draw: &mut DebugDraw) {
+        for spine in self.spines.iter() {
+            for joint in spine.joints.iter() {
+                draw.circle(joint.translation, joint.size, debug::WHITE);
+            }
+            for pair in spine.joints.windows(2) {
+                draw.line(pair[0].translation, pair[1].translation, debug::YELLOW);
+            }
+        }
+        for obstacle in self.obstacles.iter() {
+            draw.circle(obstacle.translation, obstacle.radius, debug::GREY);
+        }
+        self.boids.draw_debug(draw);
+    }
+}
+
+/// Snapshots and comparisons.
+///
+/// The snapshot is line based text, one entity per line. Floats are written
+/// as their raw bits so that a restored state steps exactly like the original.
+impl PondState {
+    pub fn serialize(&self) -> String {
+        let mut out = String::new();
+        let (rng_state, rng_inc) = self.rng.to_raw();
+        writeln!(out, "{HEADER}").unwrap();
+        writeln!(out, "seed {}", self.seed).unwrap();
+        writeln!(out, "tick {}", self.tick).unwrap();
+        writeln!(out, "rng {rng_state:016x} {rng_inc:016x}").unwrap();
+        writeln!(out, "cursor {}", bits(&[self.cursor.x, self.cursor.y])).unwrap();
+        for boid in self.boids.boids().iter() {
+            let t = boid.translation;
+            let v = boid.velocity;
+            let values = [t.x, t.y, v.x, v.y, boid.depth, boid.target_depth];
+            writeln!(out, "boid {}", bits(&values)).unwrap();
+        }
+        for spine in self.spines.iter() {
+            let joints = spine
+                .joints
+                .iter()
+                .flat_map(|j| [j.translation.x, j.translation.y, j.size])
+                .collect::<Vec<_>>();
+            writeln!(out, "spine {}", bits(&joints)).unwrap();
+        }
+        writeln!(out, "next_food_id {}", self.next_food_id).unwrap();
+        for food in self.food.iter() {
+            let t = food.translation;
+            writeln!(out, "food {} {}", food.id, bits(&[t.x, t.y, food.amount])).unwrap();
+        }
+        for click in self.clicks.iter() {
+            let t = click.translation;
+            writeln!(out, "click {} {}", click.tick, bits(&[t.x, t.y])).unwrap();
+        }
+        for obstacle in self.obstacles.iter() {
+            let t = obstacle.translation;
+            writeln!(out, "obstacle {}", bits(&[t.x, t.y, obstacle.radius])).unwrap();
+        }
+        out
+    }
+
+    pub fn deserialize(src: &str) -> Result<Self, String> {
+        let mut lines = src
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        match lines.next() {
+            Some((_, header)) if header.trim() == HEADER => {}
+            Some((_, header)) => return Err(format!("unknown state header `{header}`")),
+            None => return Err("missing header".to_string()),
+        }
+
+        let mut seed = None;
+        let mut tick = None;
+        let mut rng = None;
+        let mut cursor = Vec2::ZERO;
+        let mut boids = Vec::new();
+        let mut spines = Vec::new();
+        let mut food = Vec::new();
+        let mut next_food_id = None;
+        let mut clicks = Vec::new();
+        let mut obstacles = Vec::new();
+
+        for (n, line) in lines {
+            let n = n + 1;
+            let (kind, rest) = line.split_once(' ').unwrap_or((line, ""));
+            let floats = |rest: &str, count: usize| -> Result<Vec<f32>, String> {
+                let values = rest
+                    .split_whitespace()
+                    .map(parse_bits)
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| format!("line {n}: malformed float bits"))?;
+                if values.len() != count {
+                    return Err(format!(
+                        "line {n}: expected {count} values, found {}",
+                        values.len()
+                    ));
+                }
+                Ok(values)
+            };
+
+            match kind {
+                "seed" => seed = rest.trim().parse().ok(),
+                "tick" => tick = rest.trim().parse().ok(),
+                "rng" => {
+                    let mut parts = rest
+                        .split_whitespace()
+                        .map(|s| u64::from_str_radix(s, 16).ok());
+                    if let (Some(Some(state)), Some(Some(inc))) = (parts.next(), parts.next()) {
+                        rng = Some(Rng::from_raw(state, inc));
+                    }
+                }
+                "cursor" => {
+                    let v = floats(rest, 2)?;
+                    cursor = Vec2::new(v[0], v[1]);
+                }
+                "boid" => {
+                    let v = floats(rest, 6)?;
+                    boids.push(Boid {
+                        translation: Vec2::new(v[0], v[1]),
+                        velocity: Vec2::new(v[2], v[3]),
+                        depth: v[4],
+                        target_depth: v[5],
+                    });
+                }
+                "spine" => {
+                    let v = floats(rest, SEGMENTS * 3)?;
+                    spines.push(Spine {
+                        joints: core::array::from_fn(|i| Joint {
+                            translation: Vec2::new(v[i * 3], v[i * 3 + 1]),
+                            size: v[i * 3 + 2],
+                        }),
+                    });
+                }
+                "next_food_id" => next_food_id = rest.trim().parse().ok(),
+                "food" => {
+                    let (id, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+                    let id = id
+                        .parse()
+                        .map_err(|_| format!("line {n}: malformed food id `{id}`"))?;
+                    let v = floats(rest, 3)?;
+                    food.push(Food {
+                        id,
+                        translation: Vec2::new(v[0], v[1]),
+                        amount: v[2],
+                    });
+                }
+                "click" => {
+                    let (tick, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+                    let tick = tick
+                        .parse()
+                        .map_err(|_| format!("line {n}: malformed click tick `{tick}`"))?;
+                    let v = floats(rest, 2)?;
+                    clicks.push(Click {
+                        tick,
+                        translation: Vec2::new(v[0], v[1]),
+                    });
+                }
+                "obstacle" => {
+                    let v = floats(rest, 3)?;
+                    obstacles.push(Obstacle {
+                        translation: Vec2::new(v[0], v[1]),
+                        radius: v[2],
+                    });
+                }
+                _ => return Err(format!("line {n}: unknown entry `{kind}`")),
+            }
+        }
+
+        if boids.len() != spines.len() {
+            return Err(format!(
+                "{} boids but {} spines, every boid needs a spine",
+                boids.len(),
+                spines.len()
+            ));
+        }
+
+        Ok(Self {
+            seed: seed.ok_or("missing or malformed seed")?,
+            tick: tick.ok_or("missing or malformed tick")?,
+            rng: rng.ok_or("missing or malformed rng")?,
+            cursor,
+            boids: BoidMemory::with_boids(boids),
+            spines,
+            food,
+            next_food_id: next_food_id.ok_or("missing or malformed next_food_id")?,
+            clicks,
+            obstacles,
+        })
+    }
+
+    /// Human readable list of everything that differs from `other`, empty if
+    /// the states are identical.
+    pub fn diff(&self, other: &Self) -> Vec<String> {
+        let mut out = Vec::new();
+        diff_field(&mut out, "seed", &self.seed, &other.seed);
+        diff_field(&mut out, "tick", &self.tick, &other.tick);
+        diff_field(&mut out, "rng", &self.rng.to_raw(), &other.rng.to_raw());
+        diff_field(&mut out, "cursor", &self.cursor, &other.cursor);
+        diff_list(&mut out, "boid", self.boids.boids(), other.boids.boids());
+        diff_list(&mut out, "spine", &self.spines, &other.spines);
+        diff_field(
+            &mut out,
+            "next food id",
+            &self.next_food_id,
+            &other.next_food_id,
+        );
+        diff_list(&mut out, "food", &self.food, &other.food);
+        diff_list(&mut out, "click", &self.clicks, &other.clicks);
+        diff_list(&mut out, "obstacle", &self.obstacles, &other.obstacles);
+        out
+    }
+}
+
+fn diff_field<T: PartialEq + std::fmt::Debug>(out: &mut Vec<String>, name: &str, a: &T, b: &T) {
+    if a != b {
+        out.push(format!("{name}: {a:?} -> {b:?}"));
+    }
+}
+
+fn diff_list<T: PartialEq + std::fmt::Debug>(out: &mut Vec<String>, name: &str, a: &[T], b: &[T]) {
+    if a.len() != b.len() {
+        out.push(format!("{name} count: {} -> {}", a.len(), b.len()));
+    }
+    for (i, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+        diff_field(out, &format!("{name} {i}"), a, b);
+    }
+}
+
+fn bits(values: &[f32]) -> String {
+    values
+        .iter()
+        .map(|v| format!("{:08x}", v.to_bits()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::TIMESTEP;
+
+    /// A minute of simulation.
+    const TICKS: usize = 3600;
+
+    #[test]
+    fn fish_stay_in_the_pond() {
+        // the bounds only nudge fish back a little every tick, so they
+        // overshoot by a fair bit before turning around
+        let limit = boids::BOUNDS * 4.0;
+        for seed in [0, 1, 42, crate::rng::DEFAULT_SEED] {
+            let mut state = PondState::new(seed);
+            let count = state.boids().boids().len();
+            for _ in 0..TICKS {
+                state.step(TIMESTEP, &[]);
+                for boid in state.boids().boids().iter() {
+                    let t = boid.translation;
+                    assert!(t.abs().cmplt(limit).all(), "seed {seed}: fish at {t}");
+                    assert!((0.0..=1.0).contains(&boid.depth), "{}", boid.depth);
+                }
+            }
+            assert_eq!(state.tick(), TICKS as u64);
+            assert_eq!(state.boids().boids().len(), count);
+            assert_eq!(state.spines().len(), count);
+        }
+    }
+
+    #[test]
+    fn spawn_adds_a_fish_with_a_spine() {
+        let mut state = PondState::new(1);
+        let count = state.boids().boids().len();
+        state.step(TIMESTEP, &[Input::Spawn(Vec2::new(1e6, 0.0))]);
+        assert_eq!(state.boids().boids().len(), count + 1);
+        assert_eq!(state.spines().len(), count + 1);
+        let spawned = state.boids().boids().last().unwrap();
+        assert!(spawned.translation.x <= boids::BOUNDS.x + 10.0);
+    }
+
+    /// A few seconds in, with every kind of entity present.
+    fn busy_state(seed: u64) -> PondState {
+        let mut state = PondState::new(seed);
+        state.drop_food(Vec2::new(30.0, -20.0));
+        state.add_obstacle(Vec2::new(-50.0, 60.0), 25.0);
+        for tick in 0..300 {
+            let inputs = [
+                Input::Cursor(Vec2::splat(tick as f32)),
+                Input::Click(Vec2::new(10.0, -tick as f32)),
+            ];
+            state.step(TIMESTEP, if tick % 100 == 0 { &inputs } else { &[] });
+        }
+        state
+    }
+
+    #[test]
+    fn snapshot_round_trips() {
+        let state = busy_state(3);
+        assert!(!state.food().is_empty());
+        let loaded = PondState::deserialize(&state.serialize()).unwrap();
+        assert_eq!(loaded.diff(&state), Vec::<String>::new());
+        assert_eq!(loaded, state);
+    }
+
+    #[test]
+    fn diff_names_what_changed() {
+        let state = busy_state(3);
+        let mut other = state.clone();
+        other.cursor.x += 1.0;
+        other.spawn(Vec2::ZERO);
+        let diff = state.diff(&other);
+        assert!(
+            diff.iter().any(|line| line.starts_with("cursor: ")),
+            "{diff:?}"
+        );
+        assert!(diff.contains(&"boid count: 8 -> 9".to_string()), "{diff:?}");
+        assert!(
+            diff.contains(&"spine count: 8 -> 9".to_string()),
+            "{diff:?}"
+        );
+        assert!(
+            !diff.iter().any(|line| line.starts_with("seed")),
+            "{diff:?}"
+        );
+        assert!(PondState::new(1).diff(&PondState::new(2)).len() > 2);
+    }
+
+    #[test]
+    fn same_seed_and_inputs_step_the_same() {
+        assert_eq!(busy_state(5), busy_state(5));
+        assert_eq!(busy_state(5).serialize(), busy_state(5).serialize());
+        assert_ne!(busy_state(5), busy_state(6));
+    }
+
+    #[test]
+    fn clicks_last_for_a_frame() {
+        let mut state = PondState::new(1);
+        state.step(TIMESTEP, &[]);
+        state.step(TIMESTEP, &[Input::Click(Vec2::ONE)]);
+        let click = Click {
+            tick: 1,
+            translation: Vec2::ONE,
+        };
+        // still there after a frame that started on the click's tick and ran
+        // as many ticks as a frame can
+        for _ in 1..MAX_TICKS_PER_FRAME {
+            state.step(TIMESTEP, &[]);
+        }
+        assert_eq!(state.clicks(), [click]);
+        state.step(TIMESTEP, &[]);
+        assert_eq!(state.clicks(), []);
+    }
+
+    #[test]
+    fn food_input_brings_fish_up_to_eat() {
+        let mut state = PondState::new(1);
+        let p = state.boids().boids()[0].translation;
+        state.step(TIMESTEP, &[Input::Food(p)]);
+        assert_eq!(state.food().len(), 1);
+        for _ in 0..TICKS {
+            state.step(TIMESTEP, &[]);
+        }
+        // only fish that came up to the surface can have eaten
+        let eaten = state
+            .food()
+            .first()
+            .is_none_or(|food| food.amount < FOOD_AMOUNT);
+        assert!(eaten, "{:?}", state.food());
+    }
+}
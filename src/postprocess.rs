@@ -1,161 +1,1010 @@
-use crate::{shader::uniform, texture};
+use crate::{
+    ripple::RippleField,
+    shader::uniform,
+    target::{RenderTarget, TargetFormat},
+    texture,
+};
+use glam::{Mat4, Vec2, Vec3};
 use glow::HasContext;
 
-pub struct PostProcessor {
-    data: PostProcessorData,
-    shader: glow::Program,
+/// Directional light on the water surface.
+#[derive(Debug, Clone, Copy)]
+pub struct Sun {
+    /// Points from the surface towards the sun, `z` is up out of the water.
+    pub direction: Vec3,
+    pub color: Vec3,
+    /// Blinn-Phong exponent, higher is a smaller, sharper highlight.
+    pub shininess: f32,
+}
+
+impl Default for Sun {
+    fn default() -> Self {
+        Self {
+            direction: Vec3::new(-0.3, 0.3, 1.0),
+            color: Vec3::new(1.0, 0.95, 0.8),
+            shininess: 128.0,
+        }
+    }
+}
+
+/// Everything the passes read that is produced while drawing the scene.
+pub struct PostInputs<'a> {
+    pub ripples: &'a RippleField,
+    /// Screen space wake heights, see [`crate::wake::WakeRenderer`].
+    pub wakes: glow::Texture,
+    /// The camera the scene was drawn with, so the water lines up with it.
+    pub view_proj: Mat4,
+    pub sun: &'a Sun,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WaterParams {
+    /// Screen uv offset per unit of surface slope.
+    pub refraction: f32,
+    /// Color deep fish fade towards.
+    pub depth_tint: Vec3,
+    /// How much of `depth_tint` a fish on the floor takes on.
+    pub depth_attenuation: f32,
+    /// Blur radius in pixels for a fish on the floor.
+    pub depth_blur: f32,
+}
+
+impl Default for WaterParams {
+    fn default() -> Self {
+        Self {
+            refraction: 0.05,
+            depth_tint: Vec3::new(0.05, 0.3, 0.3),
+            depth_attenuation: 0.6,
+            depth_blur: 3.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VignetteParams {
+    /// Darkening in the corners, 0 is none.
+    pub strength: f32,
+    /// Distance from the center, in uv, where darkening starts.
+    pub radius: f32,
+    pub softness: f32,
+}
+
+impl Default for VignetteParams {
+    fn default() -> Self {
+        Self {
+            strength: 0.35,
+            radius: 0.45,
+            softness: 0.4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColorGradeParams {
+    pub brightness: f32,
+    /// Scales the distance from mid grey, 1 is unchanged.
+    pub contrast: f32,
+    /// 0 is greyscale, 1 is unchanged.
+    pub saturation: f32,
+    /// Multiplied into the result.
+    pub tint: Vec3,
+}
+
+impl Default for ColorGradeParams {
+    fn default() -> Self {
+        Self {
+            brightness: 0.0,
+            contrast: 1.05,
+            saturation: 1.1,
+            tint: Vec3::ONE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BlurParams {
+    /// Pixels between the taps of the separable gaussian.
+    pub spread: f32,
+}
+
+impl Default for BlurParams {
+    fn default() -> Self {
+        Self { spread: 1.5 }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BloomParams {
+    /// Brightness where pixels start to glow, with a soft knee below it.
+    pub threshold: f32,
+    /// Strength of the glow added back onto the image.
+    pub intensity: f32,
+    /// Spread of the upsampling filter in texels of each level, larger
+    /// values give a wider, softer glow.
+    pub radius: f32,
+}
+
+impl Default for BloomParams {
+    fn default() -> Self {
+        Self {
+            threshold: 0.9,
+            intensity: 0.6,
+            radius: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FxaaParams {
+    /// Longest edge search, in pixels.
+    pub span_max: f32,
+    /// Contrast an edge needs relative to its brightness before it is
+    /// smoothed, lower values blur more.
+    pub reduce_mul: f32,
+}
+
+impl Default for FxaaParams {
+    fn default() -> Self {
+        Self {
+            span_max: 8.0,
+            reduce_mul: 1.0 / 8.0,
+        }
+    }
+}
+
+/// Curve mapping hdr scene values into the displayable range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Tonemapper {
+    /// Narkowicz's fit of the ACES filmic curve.
+    #[default]
+    Aces,
+    Reinhard,
+    /// Minimal polynomial fit of Blender's AgX, desaturates highlights
+    /// instead of skewing their hue.
+    AgX,
+}
+
+impl Tonemapper {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Aces => Self::Reinhard,
+            Self::Reinhard => Self::AgX,
+            Self::AgX => Self::Aces,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TonemapParams {
+    pub tonemapper: Tonemapper,
+    /// Scene values are multiplied by `2^exposure` before tonemapping.
+    pub exposure: f32,
+}
+
+impl Default for TonemapParams {
+    fn default() -> Self {
+        Self {
+            tonemapper: Tonemapper::default(),
+            exposure: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PassKind {
+    Water(WaterParams),
+    Vignette(VignetteParams),
+    ColorGrade(ColorGradeParams),
+    Blur(BlurParams),
+    /// Should come before [`PassKind::Tonemap`], it needs the unclipped
+    /// highlights.
+    Bloom(BloomParams),
+    /// Belongs near the end of the chain, everything after it works on
+    /// clipped colors.
+    Tonemap(TonemapParams),
+    /// Smooths jagged edges in the final image, a cheaper stand in for
+    /// multisampling. Expects tonemapped colors.
+    Fxaa(FxaaParams),
+}
+
+impl PassKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Water(_) => "water",
+            Self::Vignette(_) => "vignette",
+            Self::ColorGrade(_) => "color grade",
+            Self::Blur(_) => "blur",
+            Self::Bloom(_) => "bloom",
+            Self::Tonemap(_) => "tonemap",
+            Self::Fxaa(_) => "fxaa",
+        }
+    }
+
+    /// Number of draws the pass needs, each reading the previous one.
+    fn subpasses(&self) -> usize {
+        match self {
+            Self::Blur(_) => 2,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Pass {
+    pub enabled: bool,
+    pub kind: PassKind,
+}
+
+impl Pass {
+    pub fn new(kind: PassKind) -> Self {
+        Self {
+            enabled: true,
+            kind,
+        }
+    }
+
+    pub fn disabled(kind: PassKind) -> Self {
+        Self {
+            enabled: false,
+            kind,
+        }
+    }
+}
+
+struct Shaders {
+    water: glow::Program,
+    vignette: glow::Program,
+    color_grade: glow::Program,
+    blur: glow::Program,
+    tonemap: glow::Program,
+    bloom_down: glow::Program,
+    bloom_up: glow::Program,
+    bloom: glow::Program,
+    fxaa: glow::Program,
+    copy: glow::Program,
+}
+
+/// Turns the scene into the final image with an ordered list of fullscreen
+/// passes.
+///
+/// Each enabled pass reads the output of the one before it. Intermediate
+/// results ping-pong between two targets and the last pass draws straight
+/// into whatever framebuffer was bound. `passes` can be edited freely
+/// between frames to add, reorder or toggle passes.
+pub struct PostChain {
+    pub passes: Vec<Pass>,
+    scene: SceneTarget,
+    targets: [RenderTarget; 2],
+    /// Successively halved copies of the bright parts of the image, the
+    /// first is half the screen size.
+    bloom: Vec<(RenderTarget, Vec2)>,
+    shaders: Shaders,
     dudv: glow::Texture,
+    ripples: glow::Texture,
+    size: Vec2,
     time: f32,
 }
 
-impl PostProcessor {
+impl PostChain {
     pub fn new(gl: &glow::Context, width: usize, height: usize) -> Self {
-        let shader = crate::compile_shader!(gl, "shaders/postprocess.vert", "shaders/water.frag");
+        let samples = msaa_samples(gl);
+        glazer::log!("[INFO] scene multisampling: {samples}x");
+        let shaders = Shaders {
+            water: crate::compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/water.frag",
+                "shaders/noise.glsl",
+                "shaders/surface.glsl"
+            ),
+            vignette: crate::compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/vignette.frag"
+            ),
+            color_grade: crate::compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/color_grade.frag"
+            ),
+            blur: crate::compile_shader!(gl, "shaders/postprocess.vert", "shaders/blur.frag"),
+            tonemap: crate::compile_shader!(gl, "shaders/postprocess.vert", "shaders/tonemap.frag"),
+            bloom_down: crate::compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/bloom_down.frag"
+            ),
+            bloom_up: crate::compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/bloom_up.frag"
+            ),
+            bloom: crate::compile_shader!(gl, "shaders/postprocess.vert", "shaders/bloom.frag"),
+            fxaa: crate::compile_shader!(gl, "shaders/postprocess.vert", "shaders/fxaa.frag"),
+            copy: crate::compile_shader!(gl, "shaders/postprocess.vert", "shaders/copy.frag"),
+        };
         Self {
-            data: PostProcessorData::new(gl, width, height),
-            shader,
+            passes: vec![
+                Pass::new(PassKind::Water(WaterParams::default())),
+                Pass::disabled(PassKind::Blur(BlurParams::default())),
+                Pass::disabled(PassKind::ColorGrade(ColorGradeParams::default())),
+                Pass::new(PassKind::Bloom(BloomParams::default())),
+                Pass::new(PassKind::Vignette(VignetteParams::default())),
+                Pass::new(PassKind::Tonemap(TonemapParams::default())),
+                Pass {
+                    // only needed where the scene is not multisampled
+                    enabled: samples == 0,
+                    kind: PassKind::Fxaa(FxaaParams::default()),
+                },
+            ],
+            scene: SceneTarget::new(gl, width, height, samples),
+            targets: [
+                RenderTarget::new(gl, width, height, TargetFormat::Rgba16F),
+                RenderTarget::new(gl, width, height, TargetFormat::Rgba16F),
+            ],
+            bloom: bloom_levels(width, height)
+                .map(|size| {
+                    let target = RenderTarget::new(
+                        gl,
+                        size.x as usize,
+                        size.y as usize,
+                        TargetFormat::Rgba16F,
+                    );
+                    (target, size)
+                })
+                .collect(),
+            shaders,
             dudv: texture::load_texture(gl, "assets/dudv.jpg"),
+            ripples: ripple_texture(gl),
+            size: Vec2::new(width as f32, height as f32),
             time: 0.0,
         }
     }
 
     pub fn resize(&mut self, gl: &glow::Context, width: usize, height: usize) {
-        self.data.resize(gl, width, height);
+        self.scene.resize(gl, width, height);
+        for target in self.targets.iter() {
+            target.resize(gl, width, height);
+        }
+        let levels = bloom_levels(width, height).collect::<Vec<_>>();
+        self.bloom.truncate(levels.len());
+        for (i, size) in levels.into_iter().enumerate() {
+            if let Some((target, level)) = self.bloom.get_mut(i) {
+                target.resize(gl, size.x as usize, size.y as usize);
+                *level = size;
+            } else {
+                let target =
+                    RenderTarget::new(gl, size.x as usize, size.y as usize, TargetFormat::Rgba16F);
+                self.bloom.push((target, size));
+            }
+        }
+        self.size = Vec2::new(width as f32, height as f32);
     }
 
+    /// The parameters of the first tonemap pass, if there is one.
+    pub fn tonemap_mut(&mut self) -> Option<&mut TonemapParams> {
+        self.passes
+            .iter_mut()
+            .find_map(|pass| match &mut pass.kind {
+                PassKind::Tonemap(params) => Some(params),
+                _ => None,
+            })
+    }
+
+    /// Binds and clears the scene target, fish depth writes start disabled.
     pub fn bind_framebuffer(&self, gl: &glow::Context) {
         unsafe {
-            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.data.framebuffer));
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.scene.draw_framebuffer()));
+            gl.draw_buffers(&[glow::COLOR_ATTACHMENT0, glow::COLOR_ATTACHMENT1]);
+            gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+            self.write_fish_depth(gl, false);
+        }
+    }
+
+    /// Only the fish shaders output a depth, anything else drawn while this
+    /// is enabled would leave garbage in it.
+    pub fn write_fish_depth(&self, gl: &glow::Context, enabled: bool) {
+        let second = if enabled {
+            glow::COLOR_ATTACHMENT1
+        } else {
+            glow::NONE
+        };
+        unsafe {
+            gl.draw_buffers(&[glow::COLOR_ATTACHMENT0, second]);
         }
     }
 
-    pub fn render_to_active_framebuffer(&mut self, gl: &glow::Context, dt: f32) {
+    /// Runs every enabled pass over the scene, the last one drawing into the
+    /// framebuffer bound when this is called.
+    pub fn render_to_active_framebuffer(
+        &mut self,
+        gl: &glow::Context,
+        inputs: &PostInputs,
+        dt: f32,
+    ) {
         self.time += dt;
         unsafe {
-            gl.use_program(Some(self.shader));
-            gl.bind_vertex_array(Some(self.data.vao));
+            let output = gl.get_parameter_framebuffer(glow::FRAMEBUFFER_BINDING);
+            self.scene.resolve(gl);
+            self.upload_ripples(gl, inputs.ripples);
 
+            gl.disable(glow::DEPTH_TEST);
+            gl.disable(glow::BLEND);
+            gl.bind_vertex_array(Some(self.scene.vao));
             gl.active_texture(glow::TEXTURE0);
-            gl.bind_texture(glow::TEXTURE_2D, Some(self.data.texture));
+
+            let draws = self
+                .passes
+                .iter()
+                .filter(|pass| pass.enabled)
+                .flat_map(|pass| (0..pass.kind.subpasses()).map(move |sub| (pass.kind, sub)))
+                .collect::<Vec<_>>();
+
+            let mut source = self.scene.texture;
+            let mut ping = 0;
+            for (n, (kind, sub)) in draws.iter().enumerate() {
+                if let PassKind::Bloom(params) = kind {
+                    self.render_bloom(gl, params, source);
+                }
+                if n + 1 == draws.len() {
+                    gl.bind_framebuffer(glow::FRAMEBUFFER, output);
+                } else {
+                    self.targets[ping].bind(gl);
+                }
+                gl.bind_texture(glow::TEXTURE_2D, Some(source));
+                self.draw_pass(gl, kind, *sub, inputs);
+                source = self.targets[ping].texture;
+                ping ^= 1;
+            }
+
+            if draws.is_empty() {
+                gl.bind_framebuffer(glow::FRAMEBUFFER, output);
+                gl.bind_texture(glow::TEXTURE_2D, Some(self.scene.texture));
+                gl.use_program(Some(self.shaders.copy));
+                gl.draw_arrays(glow::TRIANGLES, 0, 6);
+            }
+
+            gl.bind_vertex_array(None);
+            gl.enable(glow::BLEND);
+        }
+    }
+
+    /// Draws one fullscreen pass, the source is bound to texture unit 0.
+    unsafe fn draw_pass(
+        &self,
+        gl: &glow::Context,
+        kind: &PassKind,
+        sub: usize,
+        inputs: &PostInputs,
+    ) {
+        unsafe {
+            match kind {
+                PassKind::Water(params) => self.draw_water(gl, params, inputs),
+                PassKind::Vignette(params) => {
+                    let shader = self.shaders.vignette;
+                    gl.use_program(Some(shader));
+                    uniform(gl, shader, "strength", |location| {
+                        gl.uniform_1_f32(location, params.strength);
+                    });
+                    uniform(gl, shader, "radius", |location| {
+                        gl.uniform_1_f32(location, params.radius);
+                    });
+                    uniform(gl, shader, "softness", |location| {
+                        gl.uniform_1_f32(location, params.softness);
+                    });
+                }
+                PassKind::ColorGrade(params) => {
+                    let shader = self.shaders.color_grade;
+                    gl.use_program(Some(shader));
+                    uniform(gl, shader, "brightness", |location| {
+                        gl.uniform_1_f32(location, params.brightness);
+                    });
+                    uniform(gl, shader, "contrast", |location| {
+                        gl.uniform_1_f32(location, params.contrast);
+                    });
+                    uniform(gl, shader, "saturation", |location| {
+                        gl.uniform_1_f32(location, params.saturation);
+                    });
+                    uniform(gl, shader, "tint", |location| {
+                        let t = params.tint;
+                        gl.uniform_3_f32(location, t.x, t.y, t.z);
+                    });
+                }
+                PassKind::Tonemap(params) => {
+                    let shader = self.shaders.tonemap;
+                    gl.use_program(Some(shader));
+                    uniform(gl, shader, "tonemapper", |location| {
+                        gl.uniform_1_i32(location, params.tonemapper as i32);
+                    });
+                    uniform(gl, shader, "exposure", |location| {
+                        gl.uniform_1_f32(location, params.exposure.exp2());
+                    });
+                }
+                PassKind::Bloom(params) => {
+                    let shader = self.shaders.bloom;
+                    gl.use_program(Some(shader));
+                    gl.active_texture(glow::TEXTURE0 + 1);
+                    let bright = self.bloom.first().map(|(target, _)| target.texture);
+                    gl.bind_texture(glow::TEXTURE_2D, bright);
+                    gl.active_texture(glow::TEXTURE0);
+                    uniform(gl, shader, "image", |location| {
+                        gl.uniform_1_i32(location, 0);
+                    });
+                    uniform(gl, shader, "bloom", |location| {
+                        gl.uniform_1_i32(location, 1);
+                    });
+                    uniform(gl, shader, "intensity", |location| {
+                        let intensity = if bright.is_some() {
+                            params.intensity
+                        } else {
+                            0.0
+                        };
+                        gl.uniform_1_f32(location, intensity);
+                    });
+                }
+                PassKind::Fxaa(params) => {
+                    let shader = self.shaders.fxaa;
+                    gl.use_program(Some(shader));
+                    uniform(gl, shader, "texel", |location| {
+                        let texel = 1.0 / self.size;
+                        gl.uniform_2_f32(location, texel.x, texel.y);
+                    });
+                    uniform(gl, shader, "span_max", |location| {
+                        gl.uniform_1_f32(location, params.span_max);
+                    });
+                    uniform(gl, shader, "reduce_mul", |location| {
+                        gl.uniform_1_f32(location, params.reduce_mul);
+                    });
+                }
+                PassKind::Blur(params) => {
+                    let shader = self.shaders.blur;
+                    gl.use_program(Some(shader));
+                    let texel = params.spread / self.size;
+                    let direction = if sub == 0 {
+                        Vec2::new(texel.x, 0.0)
+                    } else {
+                        Vec2::new(0.0, texel.y)
+                    };
+                    uniform(gl, shader, "direction", |location| {
+                        gl.uniform_2_f32(location, direction.x, direction.y);
+                    });
+                }
+            }
+            gl.draw_arrays(glow::TRIANGLES, 0, 6);
+        }
+    }
+
+    unsafe fn draw_water(&self, gl: &glow::Context, params: &WaterParams, inputs: &PostInputs) {
+        unsafe {
+            let shader = self.shaders.water;
+            gl.use_program(Some(shader));
 
             gl.active_texture(glow::TEXTURE0 + 1);
             gl.bind_texture(glow::TEXTURE_2D, Some(self.dudv));
 
-            uniform(gl, self.shader, "framebuffer", |location| {
+            gl.active_texture(glow::TEXTURE0 + 2);
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.ripples));
+
+            gl.active_texture(glow::TEXTURE0 + 3);
+            gl.bind_texture(glow::TEXTURE_2D, Some(inputs.wakes));
+
+            gl.active_texture(glow::TEXTURE0 + 4);
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.scene.fish_depth));
+            gl.active_texture(glow::TEXTURE0);
+
+            uniform(gl, shader, "framebuffer", |location| {
                 gl.uniform_1_i32(location, 0);
             });
-            uniform(gl, self.shader, "dudv", |location| {
+            uniform(gl, shader, "dudv", |location| {
                 gl.uniform_1_i32(location, 1);
             });
-            uniform(gl, self.shader, "time", |location| {
+            uniform(gl, shader, "ripples", |location| {
+                gl.uniform_1_i32(location, 2);
+            });
+            uniform(gl, shader, "wakes", |location| {
+                gl.uniform_1_i32(location, 3);
+            });
+            uniform(gl, shader, "fish_depth", |location| {
+                gl.uniform_1_i32(location, 4);
+            });
+            uniform(gl, shader, "inv_view_proj", |location| {
+                let inv_view_proj = inputs.view_proj.inverse();
+                gl.uniform_matrix_4_f32_slice(location, false, &inv_view_proj.to_cols_array());
+            });
+            uniform(gl, shader, "ripple_size", |location| {
+                let size = inputs.ripples.size();
+                gl.uniform_2_f32(location, size.x, size.y);
+            });
+            uniform(gl, shader, "time", |location| {
                 gl.uniform_1_f32(location, self.time);
             });
+            uniform(gl, shader, "sun_direction", |location| {
+                let d = inputs.sun.direction.normalize_or(Vec3::Z);
+                gl.uniform_3_f32(location, d.x, d.y, d.z);
+            });
+            uniform(gl, shader, "sun_color", |location| {
+                let c = inputs.sun.color;
+                gl.uniform_3_f32(location, c.x, c.y, c.z);
+            });
+            uniform(gl, shader, "sun_shininess", |location| {
+                gl.uniform_1_f32(location, inputs.sun.shininess);
+            });
+            uniform(gl, shader, "refraction", |location| {
+                gl.uniform_1_f32(location, params.refraction);
+            });
+            uniform(gl, shader, "depth_tint", |location| {
+                let t = params.depth_tint;
+                gl.uniform_3_f32(location, t.x, t.y, t.z);
+            });
+            uniform(gl, shader, "depth_attenuation", |location| {
+                gl.uniform_1_f32(location, params.depth_attenuation);
+            });
+            uniform(gl, shader, "depth_blur", |location| {
+                gl.uniform_1_f32(location, params.depth_blur);
+            });
+        }
+    }
+
+    /// Fills the bloom levels from `source`: thresholded down the chain,
+    /// then blurred back up with each level added onto the one above it.
+    unsafe fn render_bloom(&self, gl: &glow::Context, params: &BloomParams, source: glow::Texture) {
+        unsafe {
+            let shader = self.shaders.bloom_down;
+            gl.use_program(Some(shader));
+            let mut texel = 1.0 / self.size;
+            let mut input = source;
+            for (i, (target, size)) in self.bloom.iter().enumerate() {
+                target.bind(gl);
+                gl.viewport(0, 0, size.x as i32, size.y as i32);
+                gl.bind_texture(glow::TEXTURE_2D, Some(input));
+                uniform(gl, shader, "texel", |location| {
+                    gl.uniform_2_f32(location, texel.x, texel.y);
+                });
+                // only the first level is thresholded, the rest already
+                // hold nothing but highlights
+                uniform(gl, shader, "threshold", |location| {
+                    let threshold = if i == 0 { params.threshold } else { 0.0 };
+                    gl.uniform_1_f32(location, threshold);
+                });
+                gl.draw_arrays(glow::TRIANGLES, 0, 6);
+                texel = 1.0 / *size;
+                input = target.texture;
+            }
+
+            let shader = self.shaders.bloom_up;
+            gl.use_program(Some(shader));
+            uniform(gl, shader, "radius", |location| {
+                gl.uniform_1_f32(location, params.radius);
+            });
+            gl.enable(glow::BLEND);
+            gl.blend_func(glow::ONE, glow::ONE);
+            for pair in self.bloom.windows(2).rev() {
+                let [(target, size), (smaller, smaller_size)] = pair else {
+                    unreachable!()
+                };
+                target.bind(gl);
+                gl.viewport(0, 0, size.x as i32, size.y as i32);
+                gl.bind_texture(glow::TEXTURE_2D, Some(smaller.texture));
+                uniform(gl, shader, "texel", |location| {
+                    let texel = 1.0 / *smaller_size;
+                    gl.uniform_2_f32(location, texel.x, texel.y);
+                });
+                gl.draw_arrays(glow::TRIANGLES, 0, 6);
+            }
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+            gl.disable(glow::BLEND);
 
-            gl.draw_arrays(glow::TRIANGLES, 0, 6);
-            gl.active_texture(glow::TEXTURE0);
+            gl.viewport(0, 0, self.size.x as i32, self.size.y as i32);
+        }
+    }
+
+    unsafe fn upload_ripples(&self, gl: &glow::Context, ripples: &RippleField) {
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.ripples));
+            let heights = core::slice::from_raw_parts(
+                ripples.heights().as_ptr() as *const u8,
+                core::mem::size_of_val(ripples.heights()),
+            );
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::R16F as i32,
+                ripples.width() as i32,
+                ripples.height() as i32,
+                0,
+                glow::RED,
+                glow::FLOAT,
+                glow::PixelUnpackData::Slice(Some(heights)),
+            );
+            gl.bind_texture(glow::TEXTURE_2D, None);
         }
     }
 }
 
-struct PostProcessorData {
+/// Samples per pixel of the scene.
+const MSAA_SAMPLES: i32 = 4;
+
+/// WebGL2 can only multisample renderbuffers and float ones need yet another
+/// extension, so the web build relies on [`PassKind::Fxaa`] instead.
+fn msaa_samples(gl: &glow::Context) -> i32 {
+    if cfg!(target_arch = "wasm32") {
+        0
+    } else {
+        MSAA_SAMPLES.min(unsafe { gl.get_parameter_i32(glow::MAX_SAMPLES) })
+    }
+}
+
+/// Sizes of the bloom levels for a `width` by `height` screen, halving until
+/// either side would drop below a few pixels.
+fn bloom_levels(width: usize, height: usize) -> impl Iterator<Item = Vec2> {
+    const MAX_LEVELS: usize = 6;
+    const MIN_SIZE: f32 = 4.0;
+    let screen = Vec2::new(width as f32, height as f32);
+    (1..=MAX_LEVELS)
+        .map(move |level| (screen / (1 << level) as f32).floor())
+        .take_while(|size| size.min_element() >= MIN_SIZE)
+}
+
+/// Single channel float texture for the [`RippleField`] heights, filled
+/// every frame.
+fn ripple_texture(gl: &glow::Context) -> glow::Texture {
+    unsafe {
+        let texture = gl.create_texture().unwrap();
+        gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_WRAP_S,
+            glow::CLAMP_TO_EDGE as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_WRAP_T,
+            glow::CLAMP_TO_EDGE as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MIN_FILTER,
+            glow::LINEAR as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MAG_FILTER,
+            glow::LINEAR as i32,
+        );
+        gl.bind_texture(glow::TEXTURE_2D, None);
+        texture
+    }
+}
+
+/// The scene target: color, the depth of the fish covering each pixel, and
+/// a depth buffer so shallower fish hide deeper ones.
+///
+/// With multisampling the scene is drawn into renderbuffers and resolved
+/// into the textures before post processing.
+struct SceneTarget {
     framebuffer: glow::Framebuffer,
     texture: glow::Texture,
+    fish_depth: glow::Texture,
+    depth_buffer: glow::Renderbuffer,
+    multisample: Option<Multisample>,
+    width: i32,
+    height: i32,
     vao: glow::VertexArray,
     _vbo: glow::Buffer,
 }
 
-impl PostProcessorData {
-    fn new(gl: &glow::Context, width: usize, height: usize) -> Self {
+struct Multisample {
+    framebuffer: glow::Framebuffer,
+    color: glow::Renderbuffer,
+    /// A texture rather than a renderbuffer so the resolve can pick samples
+    /// instead of averaging them.
+    fish_depth: glow::Texture,
+    depth_resolve: glow::Program,
+    samples: i32,
+}
+
+impl SceneTarget {
+    fn new(gl: &glow::Context, width: usize, height: usize, samples: i32) -> Self {
         unsafe {
-            let framebuffer = gl.create_framebuffer().unwrap();
-            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            let (vao, vbo) = fullscreen_quad(gl);
+            let multisample = (samples > 0).then(|| Multisample {
+                framebuffer: gl.create_framebuffer().unwrap(),
+                color: gl.create_renderbuffer().unwrap(),
+                fish_depth: gl.create_texture().unwrap(),
+                depth_resolve: crate::compile_shader!(
+                    gl,
+                    "shaders/postprocess.vert",
+                    "shaders/depth_resolve.frag"
+                ),
+                samples,
+            });
+            let mut data = Self {
+                framebuffer: gl.create_framebuffer().unwrap(),
+                texture: gl.create_texture().unwrap(),
+                fish_depth: gl.create_texture().unwrap(),
+                depth_buffer: gl.create_renderbuffer().unwrap(),
+                multisample,
+                width: 0,
+                height: 0,
+                vao,
+                _vbo: vbo,
+            };
+            data.resize(gl, width, height);
+            data
+        }
+    }
 
-            let texture = gl.create_texture().unwrap();
-            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
-            gl.tex_image_2d(
+    /// The framebuffer the scene is drawn into.
+    fn draw_framebuffer(&self) -> glow::Framebuffer {
+        self.multisample
+            .as_ref()
+            .map_or(self.framebuffer, |ms| ms.framebuffer)
+    }
+
+    pub fn resize(&mut self, gl: &glow::Context, width: usize, height: usize) {
+        self.width = width as i32;
+        self.height = height as i32;
+        unsafe {
+            // the scene is hdr so that bright highlights survive until the
+            // tonemapper instead of clipping
+            let formats = [
+                (self.texture, TargetFormat::Rgba16F),
+                (self.fish_depth, TargetFormat::Rgba8),
+            ];
+            for (texture, format) in formats {
+                let (internal, format, ty) = format.gl(gl);
+                gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+                gl.tex_image_2d(
+                    glow::TEXTURE_2D,
+                    0,
+                    internal as i32,
+                    width as i32,
+                    height as i32,
+                    0,
+                    format,
+                    ty,
+                    glow::PixelUnpackData::Slice(None),
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MIN_FILTER,
+                    glow::LINEAR as i32,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MAG_FILTER,
+                    glow::LINEAR as i32,
+                );
+            }
+            gl.bind_texture(glow::TEXTURE_2D, None);
+
+            let samples = self.multisample.as_ref().map_or(0, |ms| ms.samples);
+            let storage = |renderbuffer, internal| {
+                gl.bind_renderbuffer(glow::RENDERBUFFER, Some(renderbuffer));
+                gl.renderbuffer_storage_multisample(
+                    glow::RENDERBUFFER,
+                    samples,
+                    internal,
+                    width as i32,
+                    height as i32,
+                );
+            };
+            storage(self.depth_buffer, glow::DEPTH_COMPONENT24);
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.framebuffer));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
                 glow::TEXTURE_2D,
+                Some(self.texture),
                 0,
-                glow::RGB as i32,
-                width as i32,
-                height as i32,
-                0,
-                glow::RGB,
-                glow::UNSIGNED_BYTE,
-                glow::PixelUnpackData::Slice(None),
-            );
-            gl.tex_parameter_i32(
-                glow::TEXTURE_2D,
-                glow::TEXTURE_MIN_FILTER,
-                glow::LINEAR as i32,
             );
-            gl.tex_parameter_i32(
-                glow::TEXTURE_2D,
-                glow::TEXTURE_MAG_FILTER,
-                glow::LINEAR as i32,
-            );
-            gl.bind_texture(glow::TEXTURE_2D, None);
             gl.framebuffer_texture_2d(
                 glow::FRAMEBUFFER,
-                glow::COLOR_ATTACHMENT0,
+                glow::COLOR_ATTACHMENT1,
                 glow::TEXTURE_2D,
-                Some(texture),
+                Some(self.fish_depth),
                 0,
             );
 
-            if gl.check_framebuffer_status(glow::FRAMEBUFFER) != glow::FRAMEBUFFER_COMPLETE {
-                panic!("postprocessing framebuffer is not complete");
+            if let Some(ms) = &self.multisample {
+                storage(ms.color, TargetFormat::Rgba16F.gl(gl).0);
+                gl.bind_texture(glow::TEXTURE_2D_MULTISAMPLE, Some(ms.fish_depth));
+                // mixed with renderbuffers, the sample locations must be fixed
+                gl.tex_image_2d_multisample(
+                    glow::TEXTURE_2D_MULTISAMPLE,
+                    samples,
+                    TargetFormat::Rgba8.gl(gl).0 as i32,
+                    width as i32,
+                    height as i32,
+                    true,
+                );
+                gl.bind_texture(glow::TEXTURE_2D_MULTISAMPLE, None);
+                if gl.check_framebuffer_status(glow::FRAMEBUFFER) != glow::FRAMEBUFFER_COMPLETE {
+                    panic!("postprocessing resolve framebuffer is not complete");
+                }
+
+                gl.bind_framebuffer(glow::FRAMEBUFFER, Some(ms.framebuffer));
+                gl.framebuffer_renderbuffer(
+                    glow::FRAMEBUFFER,
+                    glow::COLOR_ATTACHMENT0,
+                    glow::RENDERBUFFER,
+                    Some(ms.color),
+                );
+                gl.framebuffer_texture_2d(
+                    glow::FRAMEBUFFER,
+                    glow::COLOR_ATTACHMENT1,
+                    glow::TEXTURE_2D_MULTISAMPLE,
+                    Some(ms.fish_depth),
+                    0,
+                );
             }
-            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            gl.bind_renderbuffer(glow::RENDERBUFFER, None);
 
-            let (vao, vbo) = fullscreen_quad(gl);
+            gl.framebuffer_renderbuffer(
+                glow::FRAMEBUFFER,
+                glow::DEPTH_ATTACHMENT,
+                glow::RENDERBUFFER,
+                Some(self.depth_buffer),
+            );
 
-            Self {
-                framebuffer,
-                texture,
-                vao,
-                _vbo: vbo,
+            if gl.check_framebuffer_status(glow::FRAMEBUFFER) != glow::FRAMEBUFFER_COMPLETE {
+                panic!("postprocessing framebuffer is not complete");
             }
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
         }
     }
 
-    pub fn resize(&mut self, gl: &glow::Context, width: usize, height: usize) {
+    /// Averages the color samples into [`SceneTarget::texture`] and keeps
+    /// the deepest sample in [`SceneTarget::fish_depth`], nothing to do
+    /// without multisampling.
+    ///
+    /// Averaged depth would pull the edges of deep fish towards the surface,
+    /// leaving a sharp, untinted halo around them in the water pass.
+    fn resolve(&self, gl: &glow::Context) {
+        let Some(ms) = &self.multisample else {
+            return;
+        };
         unsafe {
-            gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
-            gl.tex_image_2d(
-                glow::TEXTURE_2D,
+            gl.bind_framebuffer(glow::READ_FRAMEBUFFER, Some(ms.framebuffer));
+            gl.bind_framebuffer(glow::DRAW_FRAMEBUFFER, Some(self.framebuffer));
+            // a blit only writes the draw buffers matching the read buffer
+            gl.read_buffer(glow::COLOR_ATTACHMENT0);
+            gl.draw_buffers(&[glow::COLOR_ATTACHMENT0, glow::NONE]);
+            gl.blit_framebuffer(
                 0,
-                glow::RGB as i32,
-                width as i32,
-                height as i32,
                 0,
-                glow::RGB,
-                glow::UNSIGNED_BYTE,
-                glow::PixelUnpackData::Slice(None),
-            );
-            gl.tex_parameter_i32(
-                glow::TEXTURE_2D,
-                glow::TEXTURE_MIN_FILTER,
-                glow::LINEAR as i32,
-            );
-            gl.tex_parameter_i32(
-                glow::TEXTURE_2D,
-                glow::TEXTURE_MAG_FILTER,
-                glow::LINEAR as i32,
+                self.width,
+                self.height,
+                0,
+                0,
+                self.width,
+                self.height,
+                glow::COLOR_BUFFER_BIT,
+                glow::NEAREST,
             );
-            gl.bind_texture(glow::TEXTURE_2D, None);
 
             gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.framebuffer));
-            gl.framebuffer_texture_2d(
-                glow::FRAMEBUFFER,
-                glow::COLOR_ATTACHMENT0,
-                glow::TEXTURE_2D,
-                Some(self.texture),
-                0,
-            );
+            gl.draw_buffers(&[glow::COLOR_ATTACHMENT1]);
+            gl.viewport(0, 0, self.width, self.height);
+            gl.disable(glow::DEPTH_TEST);
+            gl.disable(glow::BLEND);
+            gl.use_program(Some(ms.depth_resolve));
+            gl.active_texture(glow::TEXTURE0);
+            gl.bind_texture(glow::TEXTURE_2D_MULTISAMPLE, Some(ms.fish_depth));
+            uniform(gl, ms.depth_resolve, "image", |location| {
+                gl.uniform_1_i32(location, 0);
+            });
+            uniform(gl, ms.depth_resolve, "samples", |location| {
+                gl.uniform_1_i32(location, ms.samples);
+            });
+            gl.bind_vertex_array(Some(self.vao));
+            gl.draw_arrays(glow::TRIANGLES, 0, 6);
+            gl.bind_vertex_array(None);
+            gl.bind_texture(glow::TEXTURE_2D_MULTISAMPLE, None);
 
-            if gl.check_framebuffer_status(glow::FRAMEBUFFER) != glow::FRAMEBUFFER_COMPLETE {
-                panic!("postprocessing framebuffer is not complete");
-            }
+            gl.draw_buffers(&[glow::COLOR_ATTACHMENT0, glow::COLOR_ATTACHMENT1]);
             gl.bind_framebuffer(glow::FRAMEBUFFER, None);
         }
     }
@@ -0,0 +1,122 @@
+//! Capturing a few seconds of the pond to disk, for sharing clips.
+//!
+//! While a [`Recording`] is running the simulation advances a fixed number
+//! of ticks per rendered frame, so the clip plays back at real speed however
+//! slowly the frames are captured.
+
+use crate::screenshot;
+use image::{
+    Delay, Frame, RgbaImage,
+    codecs::gif::{GifEncoder, Repeat},
+};
+use std::{fs::File, io::BufWriter};
+
+/// Length of a recording when `PROA_RECORD_SECONDS` is not set.
+const DEFAULT_SECONDS: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingFormat {
+    /// Numbered PNGs in a new directory.
+    Png,
+    Gif,
+}
+
+impl RecordingFormat {
+    /// GIF delays are whole hundredths of a second, at 20 fps a frame is
+    /// exactly three ticks of the default 60 Hz clock.
+    fn fps(self) -> f32 {
+        match self {
+            Self::Png => 30.0,
+            Self::Gif => 20.0,
+        }
+    }
+}
+
+enum Sink {
+    Png { dir: String },
+    Gif(GifEncoder<BufWriter<File>>),
+}
+
+pub struct Recording {
+    sink: Sink,
+    path: String,
+    frame: usize,
+    frames: usize,
+    ticks_per_frame: u64,
+    delay: Delay,
+}
+
+impl Recording {
+    /// Creates the output for a recording of `seconds`, `timestep` is the
+    /// simulation clock's.
+    pub fn start(
+        format: RecordingFormat,
+        seconds: f32,
+        timestep: f32,
+    ) -> Result<Self, image::ImageError> {
+        let ticks_per_frame = ((1.0 / format.fps()) / timestep).round().max(1.0) as u64;
+        let frame_seconds = ticks_per_frame as f32 * timestep;
+        let frames = (seconds / frame_seconds).ceil().max(1.0) as usize;
+
+        let name = screenshot::timestamped("recording");
+        let (sink, path) = match format {
+            RecordingFormat::Png => {
+                std::fs::create_dir_all(&name)?;
+                (Sink::Png { dir: name.clone() }, name)
+            }
+            RecordingFormat::Gif => {
+                let path = format!("{name}.gif");
+                let file = BufWriter::new(File::create(&path)?);
+                // quantizing every frame is the slow part, trade a little
+                // color accuracy for speed
+                let mut encoder = GifEncoder::new_with_speed(file, 10);
+                encoder.set_repeat(Repeat::Infinite)?;
+                (Sink::Gif(encoder), path)
+            }
+        };
+
+        Ok(Self {
+            sink,
+            path,
+            frame: 0,
+            frames,
+            ticks_per_frame,
+            delay: Delay::from_numer_denom_ms((frame_seconds * 1000.0).round() as u32, 1),
+        })
+    }
+
+    /// Seconds from `PROA_RECORD_SECONDS`, or a short default.
+    pub fn seconds_from_env() -> f32 {
+        std::env::var("PROA_RECORD_SECONDS")
+            .ok()
+            .and_then(|seconds| seconds.parse().ok())
+            .filter(|seconds: &f32| *seconds > 0.0)
+            .unwrap_or(DEFAULT_SECONDS)
+    }
+
+    /// Simulation ticks to run before each captured frame.
+    pub fn ticks_per_frame(&self) -> u64 {
+        self.ticks_per_frame
+    }
+
+    /// Where the frames are written.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn frames_written(&self) -> usize {
+        self.frame
+    }
+
+    /// Writes the next frame, returns true once the recording is complete.
+    pub fn push(&mut self, image: RgbaImage) -> Result<bool, image::ImageError> {
+        match &mut self.sink {
+            Sink::Png { dir } => image.save(format!("{dir}/frame-{:04}.png", self.frame))?,
+            Sink::Gif(encoder) => {
+                encoder.encode_frame(Frame::from_parts(image, 0, 0, self.delay))?
+            }
+        }
+        self.frame += 1;
+        Ok(self.frame >= self.frames)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    atlas::{Atlas, AtlasBuilder},
+    camera::Camera2D,
+    debug::DebugDraw,
+    joint::{JointRenderer, Outline},
+    overlay::DebugOverlay,
+    pebbles::PebbleRenderer,
+    pond::PondState,
+    postprocess::{Pass, PostChain, PostInputs, Sun, TonemapParams},
+    ripple::RippleField,
+    screenshot,
+    shadow::ShadowRenderer,
+    sprite::{Sprite, SpriteRenderer},
+    target::{RenderTarget, TargetFormat},
+    wake::WakeRenderer,
+};
+use glam::{Vec2, Vec3, Vec4};
+use glow::HasContext;
+
+/// World units between the surface and the pond floor.
+const FLOOR_DEPTH: f32 = 40.0;
+
+/// Every GL resource needed to draw a [`PondState`]. The renderer only ever
+/// reads the state, so anything it animates on its own is purely cosmetic.
+pub struct PondRenderer {
+    joint_renderer: JointRenderer,
+    atlas: Atlas,
+    sprite_renderer: SpriteRenderer,
+    pebble_renderer: PebbleRenderer,
+    post_chain: PostChain,
+    sun: Sun,
+    ripples: RippleField,
+    wake_renderer: WakeRenderer,
+    shadow_renderer: ShadowRenderer,
+    overlay: DebugOverlay,
+    debug: DebugDraw,
+    depths: Vec<f32>,
+}
+
+impl PondRenderer {
+    pub fn new(gl: &glow::Context, width: usize, height: usize) -> Self {
+        Self {
+            joint_renderer: JointRenderer::new(gl),
+            atlas: AtlasBuilder::new(256).build(gl),
+            sprite_renderer: SpriteRenderer::new(gl),
+            pebble_renderer: PebbleRenderer::new(gl),
+            post_chain: PostChain::new(gl, width, height),
+            sun: Sun::default(),
+            ripples: RippleField::default(),
+            wake_renderer: WakeRenderer::new(gl, width, height),
+            shadow_renderer: ShadowRenderer::new(gl, width, height),
+            overlay: DebugOverlay::new(gl),
+            debug: DebugDraw::default(),
+            depths: Vec::new(),
+        }
+    }
+
+    pub fn resize(&mut self, gl: &glow::Context, width: usize, height: usize) {
+        self.post_chain.resize(gl, width, height);
+        self.wake_renderer.resize(gl, width, height);
+        self.shadow_renderer.resize(gl, width, height);
+    }
+
+    pub fn sun_mut(&mut self) -> &mut Sun {
+        &mut self.sun
+    }
+
+    pub fn outline_mut(&mut self) -> &mut Outline {
+        &mut self.joint_renderer.outline
+    }
+
+    /// The post processing passes, in order, free to edit between frames.
+    pub fn passes_mut(&mut self) -> &mut Vec<Pass> {
+        &mut self.post_chain.passes
+    }
+
+    pub fn tonemap_mut(&mut self) -> Option<&mut TonemapParams> {
+        self.post_chain.tonemap_mut()
+    }
+
+    /// Renders `state` offscreen at `scale` times the `width` by `height`
+    /// window and reads it back, `dt` is passed on to
+    /// [`PondRenderer::render`].
+    ///
+    /// The scale is lowered when the frame would not fit in a texture. A
+    /// minimized window has nothing to capture and gives `None`.
+    pub fn render_image(
+        &mut self,
+        gl: &glow::Context,
+        state: &PondState,
+        camera: &Camera2D,
+        (width, height): (usize, usize),
+        scale: usize,
+        dt: f32,
+    ) -> Option<image::RgbaImage> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let max = unsafe {
+            gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE)
+                .min(gl.get_parameter_i32(glow::MAX_RENDERBUFFER_SIZE)) as usize
+        };
+        let scale = scale.min(max / width.max(height)).max(1);
+        let (w, h) = (width * scale, height * scale);
+
+        // the same view, just with more pixels per world unit
+        let mut camera = camera.clone();
+        camera.resize(w, h);
+        camera.scale_factor *= scale as f32;
+
+        if scale != 1 {
+            self.resize(gl, w, h);
+        }
+        let target = RenderTarget::new(gl, w, h, TargetFormat::Rgba8);
+        let image = unsafe {
+            target.bind(gl);
+            gl.viewport(0, 0, w as i32, h as i32);
+            self.render(gl, state, &camera, dt);
+            target.bind(gl);
+            let image = screenshot::read_pixels(gl, w, h);
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            gl.viewport(0, 0, width as i32, height as i32);
+            image
+        };
+        target.delete(gl);
+        if scale != 1 {
+            self.resize(gl, width, height);
+        }
+        Some(image)
+    }
+
+    /// Draws `state` as seen by `camera` into the bound framebuffer, `dt`
+    /// advances the cosmetic animations.
+    pub fn render(&mut self, gl: &glow::Context, state: &PondState, camera: &Camera2D, dt: f32) {
+        let output = unsafe { gl.get_parameter_framebuffer(glow::FRAMEBUFFER_BINDING) };
+        let view_proj = camera.view_proj();
+        self.debug.clear();
+        self.ripples.update(state, dt);
+
+        self.depths.clear();
+        self.depths
+            .extend(state.boids().boids().iter().map(|boid| boid.depth));
+        let depths = &self.depths;
+
+        for food in state.food().iter() {
+            let mut sprite = Sprite::from_size(self.atlas.white(), Vec2::splat(6.0));
+            sprite.translation = food.translation.extend(20.0);
+            sprite.scale *= 0.5 + 0.5 * food.amount;
+            sprite.color = Vec4::new(0.8, 0.6, 0.3, 1.0);
+            self.sprite_renderer.push(sprite);
+        }
+
+        self.wake_renderer.render(gl, state, view_proj, dt);
+
+        // shadows fall away from the sun, further the higher a fish swims
+        // above the floor
+        let sun = self.sun.direction.normalize_or(Vec3::Z);
+        let shadow_direction = -sun.truncate() / sun.z.max(0.2);
+        self.shadow_renderer.render(
+            gl,
+            &mut self.joint_renderer,
+            state.spines(),
+            view_proj,
+            |i| shadow_direction * (1.0 - depths[i]) * FLOOR_DEPTH,
+        );
+
+        unsafe {
+            self.post_chain.bind_framebuffer(gl);
+            gl.clear_color(0.0, 0.1, 0.0, 1.0);
+            gl.clear(glow::COLOR_BUFFER_BIT | glow::DEPTH_BUFFER_BIT);
+            gl.enable(glow::DEPTH_TEST);
+            gl.enable(glow::BLEND);
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+
+            // the floor is behind everything, so it stays out of the depth buffer
+            gl.depth_mask(false);
+            self.pebble_renderer
+                .render(gl, view_proj, &self.sun, FLOOR_DEPTH, dt);
+            self.shadow_renderer.composite(gl);
+            gl.depth_mask(true);
+
+            self.post_chain.write_fish_depth(gl, true);
+            self.joint_renderer
+                .render(gl, state.spines(), depths, view_proj, dt);
+            self.post_chain.write_fish_depth(gl, false);
+
+            self.sprite_renderer.flush(gl, view_proj);
+
+            // post processing
+            gl.bind_framebuffer(glow::FRAMEBUFFER, output);
+            // gl.clear_color(0.1, 0.1, 0.1, 1.0);
+            // gl.clear(glow::COLOR_BUFFER_BIT | glow::DEPTH_BUFFER_BIT);
+            gl.disable(glow::DEPTH_TEST);
+            let inputs = PostInputs {
+                ripples: &self.ripples,
+                wakes: self.wake_renderer.texture(),
+                view_proj,
+                sun: &self.sun,
+            };
+            self.post_chain
+                .render_to_active_framebuffer(gl, &inputs, dt);
+        }
+    }
+
+    /// Shapes queued here after [`PondRenderer::render`] are drawn by
+    /// [`PondRenderer::render_debug`], the next render drops them otherwise.
+    pub fn debug_mut(&mut self) -> &mut DebugDraw {
+        &mut self.debug
+    }
+
+    /// Draws the debug shapes of `state` and anything queued through
+    /// [`PondRenderer::debug_mut`] into the bound framebuffer, after
+    /// [`PondRenderer::render`] so the water does not distort them.
+    pub fn render_debug(&mut self, gl: &glow::Context, state: &PondState, camera: &Camera2D) {
+        state.draw_debug(&mut self.debug);
+        self.overlay.render(gl, &mut self.debug, camera.view_proj());
+    }
+}
@@ -0,0 +1,297 @@
+//! Input recording and bit-for-bit playback.
+//!
+//! A replay is the seed the session started from plus every input stamped
+//! with the tick it was applied on. Floats are stored as their raw bits so
+//! that playback feeds the simulation exactly what it saw live.
+
+use glam::Vec2;
+use std::fmt::Write;
+
+const HEADER: &str = "proa-replay 1";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Input {
+    Cursor(Vec2),
+    Click(Vec2),
+    Spawn(Vec2),
+    Food(Vec2),
+}
+
+impl Input {
+    fn name(&self) -> &'static str {
+        match self {
+            Input::Cursor(_) => "cursor",
+            Input::Click(_) => "click",
+            Input::Spawn(_) => "spawn",
+            Input::Food(_) => "food",
+        }
+    }
+
+    fn position(&self) -> Vec2 {
+        match self {
+            Input::Cursor(p) | Input::Click(p) | Input::Spawn(p) | Input::Food(p) => *p,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayLog {
+    pub seed: u64,
+    pub timestep: f32,
+    pub events: Vec<(u64, Input)>,
+}
+
+impl ReplayLog {
+    pub fn new(seed: u64, timestep: f32) -> Self {
+        Self {
+            seed,
+            timestep,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, tick: u64, input: Input) {
+        debug_assert!(self.events.last().is_none_or(|(t, _)| *t <= tick));
+        self.events.push((tick, input));
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "{HEADER}").unwrap();
+        writeln!(out, "seed {}", self.seed).unwrap();
+        writeln!(out, "timestep {:08x}", self.timestep.to_bits()).unwrap();
+        for (tick, input) in self.events.iter() {
+            let p = input.position();
+            writeln!(
+                out,
+                "{tick} {} {:08x} {:08x}",
+                input.name(),
+                p.x.to_bits(),
+                p.y.to_bits()
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    pub fn deserialize(src: &str) -> Result<Self, String> {
+        let mut lines = src
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        let mut next = |what: &str| {
+            lines
+                .next()
+                .map(|(n, l)| (n + 1, l))
+                .ok_or_else(|| format!("missing {what}"))
+        };
+
+        let (_, header) = next("header")?;
+        if header.trim() != HEADER {
+            return Err(format!("unknown replay header `{header}`"));
+        }
+        let (n, seed) = next("seed")?;
+        let seed = seed
+            .strip_prefix("seed ")
+            .and_then(|s| s.trim().parse().ok())
+            .ok_or_else(|| format!("line {n}: expected `seed <u64>`"))?;
+        let (n, timestep) = next("timestep")?;
+        let timestep = timestep
+            .strip_prefix("timestep ")
+            .and_then(|s| parse_bits(s.trim()))
+            .ok_or_else(|| format!("line {n}: expected `timestep <f32 bits>`"))?;
+
+        let mut log = Self::new(seed, timestep);
+        for (n, line) in lines {
+            let n = n + 1;
+            let mut parts = line.split_whitespace();
+            let tick = parts
+                .next()
+                .and_then(|t| t.parse().ok())
+                .ok_or_else(|| format!("line {n}: expected tick"))?;
+            let kind = parts.next().unwrap_or_default();
+            let x = parts.next().and_then(parse_bits);
+            let y = parts.next().and_then(parse_bits);
+            let (Some(x), Some(y)) = (x, y) else {
+                return Err(format!("line {n}: expected position"));
+            };
+            let p = Vec2::new(x, y);
+            let input = match kind {
+                "cursor" => Input::Cursor(p),
+                "click" => Input::Click(p),
+                "spawn" => Input::Spawn(p),
+                "food" => Input::Food(p),
+                _ => return Err(format!("line {n}: unknown input `{kind}`")),
+            };
+            if log.events.last().is_some_and(|(t, _)| *t > tick) {
+                return Err(format!("line {n}: ticks are out of order"));
+            }
+            log.push(tick, input);
+        }
+
+        Ok(log)
+    }
+
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        std::fs::write(path, self.serialize())
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let src = std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+        Self::deserialize(&src)
+    }
+}
+
+pub(crate) fn parse_bits(s: &str) -> Option<f32> {
+    u32::from_str_radix(s, 16).ok().map(f32::from_bits)
+}
+
+/// Where the inputs for the next tick come from.
+#[derive(Debug, Default)]
+pub enum Replay {
+    #[default]
+    Live,
+    Recording(ReplayLog),
+    Playing {
+        log: ReplayLog,
+        next: usize,
+    },
+}
+
+impl Replay {
+    /// Collects the inputs applied on `tick`. Live inputs are recorded while
+    /// recording and ignored during playback.
+    pub fn inputs(&mut self, tick: u64, live: &mut Vec<Input>) -> Vec<Input> {
+        match self {
+            Replay::Live => std::mem::take(live),
+            Replay::Recording(log) => {
+                for input in live.iter() {
+                    log.push(tick, *input);
+                }
+                std::mem::take(live)
+            }
+            Replay::Playing { log, next } => {
+                live.clear();
+                let mut inputs = Vec::new();
+                while let Some((t, input)) = log.events.get(*next) {
+                    if *t > tick {
+                        break;
+                    }
+                    inputs.push(*input);
+                    *next += 1;
+                }
+                inputs
+            }
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match self {
+            Replay::Playing { log, next } => *next >= log.events.len(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(log: &ReplayLog) -> Vec<(u64, &'static str, u32, u32)> {
+        log.events
+            .iter()
+            .map(|(tick, input)| {
+                let p = input.position();
+                (*tick, input.name(), p.x.to_bits(), p.y.to_bits())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn round_trip_keeps_float_bits() {
+        let mut log = ReplayLog::new(u64::MAX, 1.0 / 60.0);
+        log.push(0, Input::Cursor(Vec2::new(0.1 + 0.2, -0.0)));
+        log.push(0, Input::Click(Vec2::new(f32::MIN_POSITIVE / 3.0, 1e30)));
+        log.push(7, Input::Spawn(Vec2::new(f32::NAN, -123.456)));
+        log.push(7, Input::Cursor(Vec2::new(f32::INFINITY, f32::EPSILON)));
+        log.push(9, Input::Food(Vec2::new(-f32::MAX, 0.5)));
+
+        let loaded = ReplayLog::deserialize(&log.serialize()).unwrap();
+        assert_eq!(loaded.seed, log.seed);
+        assert_eq!(loaded.timestep.to_bits(), log.timestep.to_bits());
+        assert_eq!(bits(&loaded), bits(&log));
+    }
+
+    #[test]
+    fn deserialize_rejects_bad_logs() {
+        assert!(ReplayLog::deserialize("").is_err());
+        assert!(ReplayLog::deserialize("proa-replay 0\nseed 1\ntimestep 3c888889\n").is_err());
+        let log = "proa-replay 1\nseed 1\ntimestep 3c888889\n";
+        assert!(ReplayLog::deserialize(log).is_ok());
+        assert!(ReplayLog::deserialize(&format!("{log}2 click 0 0\n1 click 0 0\n")).is_err());
+        assert!(ReplayLog::deserialize(&format!("{log}2 wave 0 0\n")).is_err());
+        assert!(ReplayLog::deserialize(&format!("{log}2 click 0\n")).is_err());
+    }
+
+    #[test]
+    fn playback_applies_inputs_on_their_tick() {
+        let mut log = ReplayLog::new(1, 1.0 / 60.0);
+        log.push(1, Input::Click(Vec2::X));
+        log.push(1, Input::Spawn(Vec2::Y));
+        log.push(3, Input::Cursor(Vec2::ONE));
+        let mut replay = Replay::Playing { log, next: 0 };
+
+        let mut live = vec![Input::Click(Vec2::ZERO)];
+        assert_eq!(replay.inputs(0, &mut live), []);
+        assert!(live.is_empty());
+        assert_eq!(
+            replay.inputs(1, &mut live),
+            [Input::Click(Vec2::X), Input::Spawn(Vec2::Y)]
+        );
+        assert_eq!(replay.inputs(2, &mut live), []);
+        assert!(!replay.is_finished());
+        assert_eq!(replay.inputs(3, &mut live), [Input::Cursor(Vec2::ONE)]);
+        assert!(replay.is_finished());
+    }
+
+    #[test]
+    fn playback_reproduces_the_session() {
+        use crate::{clock::TIMESTEP, pond::PondState};
+
+        const TICKS: u64 = 240;
+        let seed = 11;
+        let mut state = PondState::new(seed);
+        let mut replay = Replay::Recording(ReplayLog::new(seed, TIMESTEP));
+        let mut live = Vec::new();
+        for tick in 0..TICKS {
+            // inputs arrive between ticks at odd positions, like a mouse
+            let p = Vec2::new(
+                tick as f32 * 1.37 - 150.0,
+                (tick as f32 * 0.31).sin() * 200.0,
+            );
+            live.push(Input::Cursor(p));
+            match tick % 60 {
+                15 => live.push(Input::Click(p)),
+                30 => live.push(Input::Food(p * 0.5)),
+                40 => live.push(Input::Spawn(-p)),
+                _ => {}
+            }
+            let inputs = replay.inputs(tick, &mut live);
+            state.step(TIMESTEP, &inputs);
+        }
+        let Replay::Recording(log) = replay else {
+            unreachable!()
+        };
+
+        let log = ReplayLog::deserialize(&log.serialize()).unwrap();
+        let mut played = PondState::new(log.seed);
+        let mut replay = Replay::Playing { log, next: 0 };
+        for tick in 0..TICKS {
+            let inputs = replay.inputs(tick, &mut Vec::new());
+            played.step(TIMESTEP, &inputs);
+        }
+        assert!(replay.is_finished());
+        assert_eq!(played.diff(&state), Vec::<String>::new());
+        assert_eq!(played.serialize(), state.serialize());
+    }
+}
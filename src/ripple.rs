@@ -0,0 +1,147 @@
+//! Height field waves on the pond surface.
+//!
+//! Purely cosmetic, the simulation never reads it back. The field covers
+//! [`VIEW_SIZE`] around the origin and its edges are held flat, so waves
+//! reflect off them.
+
+use crate::{camera::VIEW_SIZE, clock::TIMESTEP, pond::PondState};
+use glam::Vec2;
+
+/// World units per cell.
+pub const CELL_SIZE: f32 = 5.0;
+/// Fraction of the wave kept each step.
+const DAMPING: f32 = 0.985;
+
+const FISH_RADIUS: f32 = 15.0;
+/// Height pushed per unit of fish speed, per second.
+const FISH_STRENGTH: f32 = 0.01;
+const SPLASH_RADIUS: f32 = 12.0;
+const SPLASH_STRENGTH: f32 = 2.0;
+
+pub struct RippleField {
+    width: usize,
+    height: usize,
+    current: Vec<f32>,
+    previous: Vec<f32>,
+    accumulator: f32,
+    /// Ids of the food and the number of fish seen last update, anything new
+    /// splashes.
+    food: Vec<u64>,
+    fish: usize,
+    /// Tick of the state seen last update, clicks from this tick on are new.
+    tick: u64,
+}
+
+impl Default for RippleField {
+    fn default() -> Self {
+        let width = (VIEW_SIZE.x / CELL_SIZE).ceil() as usize;
+        let height = (VIEW_SIZE.y / CELL_SIZE).ceil() as usize;
+        Self {
+            width,
+            height,
+            current: vec![0.0; width * height],
+            previous: vec![0.0; width * height],
+            accumulator: 0.0,
+            food: Vec::new(),
+            fish: 0,
+            tick: 0,
+        }
+    }
+}
+
+impl RippleField {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Row major, starting at the bottom left of the field.
+    pub fn heights(&self) -> &[f32] {
+        &self.current
+    }
+
+    /// World space size of the whole field.
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.width as f32, self.height as f32) * CELL_SIZE
+    }
+
+    /// Disturbs the surface with everything moving in `state`, then advances
+    /// the waves by `dt`.
+    pub fn update(&mut self, state: &PondState, dt: f32) {
+        for food in state.food().iter() {
+            if !self.food.contains(&food.id) {
+                self.push(food.translation, SPLASH_RADIUS, SPLASH_STRENGTH);
+            }
+        }
+        self.food.clear();
+        self.food.extend(state.food().iter().map(|food| food.id));
+
+        // a reset or a loaded state can move the tick backwards
+        if state.tick() < self.tick {
+            self.tick = 0;
+        }
+        for click in state.clicks().iter() {
+            if click.tick >= self.tick {
+                self.push(click.translation, SPLASH_RADIUS, SPLASH_STRENGTH);
+            }
+        }
+        self.tick = state.tick();
+
+        for spine in state.spines().iter().skip(self.fish) {
+            self.push(spine.head(), SPLASH_RADIUS * 2.0, SPLASH_STRENGTH);
+        }
+        self.fish = state.spines().len();
+
+        for boid in state.boids().boids().iter() {
+            // only fish near the surface disturb it
+            let strength = boid.velocity.length() * FISH_STRENGTH * (1.0 - boid.depth) * dt;
+            self.push(boid.translation, FISH_RADIUS, strength);
+        }
+
+        self.accumulator += dt;
+        while self.accumulator >= TIMESTEP {
+            self.accumulator -= TIMESTEP;
+            self.step();
+        }
+    }
+
+    /// Raises the surface around `translation` with a smooth falloff.
+    pub fn push(&mut self, translation: Vec2, radius: f32, strength: f32) {
+        let center = (translation + self.size() / 2.0) / CELL_SIZE;
+        let cells = radius / CELL_SIZE;
+        let min = (center - cells).floor().max(Vec2::ONE);
+        let max = (center + cells)
+            .ceil()
+            .min(Vec2::new(self.width as f32 - 2.0, self.height as f32 - 2.0));
+
+        for y in min.y as usize..=max.y as usize {
+            for x in min.x as usize..=max.x as usize {
+                let distance = center.distance(Vec2::new(x as f32, y as f32)) / cells;
+                if distance < 1.0 {
+                    let falloff = (1.0 - distance) * (1.0 - distance);
+                    self.current[y * self.width + x] += strength * falloff;
+                }
+            }
+        }
+    }
+
+    /// One step of the discrete wave equation, the border is never written
+    /// and stays flat.
+    fn step(&mut self) {
+        let w = self.width;
+        for y in 1..self.height - 1 {
+            for x in 1..w - 1 {
+                let i = y * w + x;
+                let neighbours = self.current[i - 1]
+                    + self.current[i + 1]
+                    + self.current[i - w]
+                    + self.current[i + w];
+                self.previous[i] = (neighbours * 0.5 - self.previous[i]) * DAMPING;
+            }
+        }
+        core::mem::swap(&mut self.current, &mut self.previous);
+    }
+}
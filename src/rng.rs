@@ -1,523 +1,268 @@
-pub fn sample_u32(index: usize) -> u32 {
-    RNG_TABLE[index % 4096]
+//! Seedable PCG32 generator.
+//!
+//! https://www.pcg-random.org/download.html
+
+use glam::Vec2;
+use std::ops::Range;
+
+pub const DEFAULT_SEED: u64 = 0x853c_49e6_748f_ea9b;
+const DEFAULT_STREAM: u64 = 0xda3e_39cb_94b9_5bdb;
+const MULTIPLIER: u64 = 6364136223846793005;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+    inc: u64,
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
 }
 
-pub fn sample_f32(index: usize) -> f32 {
-    let index = index % 4097;
-    (sample_u32(index) ^ sample_u32(index + 1)) as f32 / 1_000_000_000 as f32
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, DEFAULT_STREAM)
+    }
+
+    /// Generators with the same seed but different streams produce unrelated sequences.
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            inc: (stream << 1) | 1,
+        };
+        rng.u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.u32();
+        rng
+    }
+
+    /// Rebuilds a generator from [`Rng::to_raw`].
+    pub fn from_raw(state: u64, inc: u64) -> Self {
+        Self { state, inc }
+    }
+
+    /// The internal state, for snapshots that must resume the exact sequence.
+    pub fn to_raw(&self) -> (u64, u64) {
+        (self.state, self.inc)
+    }
+
+    /// Derive an independent generator, e.g. one per fish, without disturbing
+    /// the order in which the parent hands out values elsewhere.
+    pub fn fork(&mut self) -> Self {
+        let seed = self.u64();
+        let stream = self.u64();
+        Self::with_stream(seed, stream)
+    }
+
+    pub fn u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old.wrapping_mul(MULTIPLIER).wrapping_add(self.inc);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rot = (old >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+
+    pub fn u64(&mut self) -> u64 {
+        ((self.u32() as u64) << 32) | self.u32() as u64
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn f32(&mut self) -> f32 {
+        (self.u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    pub fn bool(&mut self, probability: f32) -> bool {
+        self.f32() < probability
+    }
+
+    /// Uniform in `[range.start, range.end)`.
+    pub fn range_f32(&mut self, range: Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * self.f32()
+    }
+
+    /// Uniform in `[range.start, range.end)`, without modulo bias.
+    ///
+    /// https://arxiv.org/abs/1805.10941
+    pub fn range_u32(&mut self, range: Range<u32>) -> u32 {
+        assert!(range.start < range.end, "empty range");
+        let span = range.end - range.start;
+        let threshold = span.wrapping_neg() % span;
+        loop {
+            let m = self.u32() as u64 * span as u64;
+            if (m as u32) >= threshold {
+                return range.start + (m >> 32) as u32;
+            }
+        }
+    }
+
+    pub fn range_usize(&mut self, range: Range<usize>) -> usize {
+        self.range_u32(range.start as u32..range.end as u32) as usize
+    }
+
+    pub fn angle(&mut self) -> f32 {
+        self.f32() * std::f32::consts::TAU
+    }
+
+    pub fn unit_vec2(&mut self) -> Vec2 {
+        Vec2::from_angle(self.angle())
+    }
+
+    /// Uniform over the area of a disk centered on the origin.
+    pub fn in_disk(&mut self, radius: f32) -> Vec2 {
+        self.unit_vec2() * radius * self.f32().sqrt()
+    }
+
+    /// Uniform over the rectangle `[-half_extents, half_extents)`.
+    pub fn in_rect(&mut self, half_extents: Vec2) -> Vec2 {
+        Vec2::new(
+            self.range_f32(-half_extents.x..half_extents.x),
+            self.range_f32(-half_extents.y..half_extents.y),
+        )
+    }
+
+    /// Normally distributed sample (Box-Muller).
+    pub fn gaussian(&mut self, mean: f32, std_dev: f32) -> f32 {
+        // 1 - f32() is in (0, 1], so ln never sees zero
+        let u1 = 1.0 - self.f32();
+        let u2 = self.f32();
+        let z = (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos();
+        mean + z * std_dev
+    }
+
+    /// Index into `weights`, picked with probability proportional to its weight.
+    ///
+    /// Returns `None` if there are no positive weights.
+    pub fn weighted(&mut self, weights: &[f32]) -> Option<usize> {
+        let total: f32 = weights.iter().filter(|w| **w > 0.0).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target = self.f32() * total;
+        let mut last = None;
+        for (i, weight) in weights.iter().enumerate() {
+            if *weight <= 0.0 {
+                continue;
+            }
+            if target < *weight {
+                return Some(i);
+            }
+            target -= weight;
+            last = Some(i);
+        }
+        // float rounding can leave a sliver past the final bucket
+        last
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.range_usize(0..items.len())])
+        }
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range_usize(0..i + 1);
+            items.swap(i, j);
+        }
+    }
 }
 
-pub const RNG_TABLE: [u32; 4096] = [
-    0x14cec936, 0x06b6c598, 0x225ddbfd, 0x1ab72e92, 0x12e2a1dc, 0x015a89c3, 0x1d432aa5, 0x10093795,
-    0x1169af8c, 0x02351fa8, 0x083eff62, 0x10668390, 0x195509cc, 0x343af35a, 0x32f8b96b, 0x385ce052,
-    0x1ed987fc, 0x31b22480, 0x05fceb4c, 0x3b7aac2e, 0x0d66f311, 0x378e1b40, 0x009a1de9, 0x147a9e3b,
-    0x05eb515a, 0x2d651a19, 0x33dfab24, 0x21de7ceb, 0x1694e328, 0x2d88fd14, 0x09ecdb35, 0x240086ca,
-    0x24edb3c0, 0x0d833046, 0x1f8b4f6c, 0x32dea00d, 0x2651ac19, 0x16dada1c, 0x2c3c72f8, 0x3844b080,
-    0x1761cf53, 0x04d8e6b1, 0x1d7a37ec, 0x2c60d322, 0x1c3de3cb, 0x33b7c97f, 0x0b3014d8, 0x184e3ae1,
-    0x11317f01, 0x0467f9aa, 0x32d675ba, 0x1e6f92a2, 0x0a751b0f, 0x08dc93de, 0x2b559b64, 0x028bf898,
-    0x28d260d8, 0x2c5528cc, 0x2faa5e47, 0x1224244d, 0x2f3c61fb, 0x1317f549, 0x09874c44, 0x29d7f598,
-    0x14319926, 0x1634f457, 0x0d7623be, 0x2188772a, 0x160ab4b4, 0x03754353, 0x114c0b28, 0x2ae754ee,
-    0x11d44a9f, 0x274a1d50, 0x339f9289, 0x04985d76, 0x20534f56, 0x1a80d191, 0x2470e0aa, 0x14e57c7b,
-    0x10036b87, 0x1f60ecd1, 0x03313951, 0x27518456, 0x3124b298, 0x1d2ab8da, 0x366702ba, 0x2f407db5,
-    0x04c75bc4, 0x2ca44dbf, 0x2b36f51d, 0x22f06cb7, 0x10d03059, 0x3ad78705, 0x031f6ecf, 0x05b59221,
-    0x32edb071, 0x008de927, 0x24d2894f, 0x06ad7285, 0x3400eea2, 0x1106ccba, 0x1e79146b, 0x33654dce,
-    0x3902aa8b, 0x261008b1, 0x1d75ac68, 0x13f34c26, 0x26ccbf5b, 0x1e7c930b, 0x395f6e1b, 0x05cb1a16,
-    0x05526b98, 0x1ce698bb, 0x3aee5b98, 0x0c737a0a, 0x2f6e285f, 0x1b8e4524, 0x2367c8b1, 0x14d89782,
-    0x00086062, 0x12162578, 0x05d54eab, 0x0ead5c8b, 0x16fb3211, 0x304ac355, 0x0db0b8bb, 0x0f427540,
-    0x04f1930c, 0x2edb57dc, 0x1a45835c, 0x2f042e6b, 0x0b49cf0a, 0x136a128f, 0x02c68c74, 0x122f9c37,
-    0x30792660, 0x1f6e64dd, 0x16ff715c, 0x0045caeb, 0x1a360ae6, 0x3b222523, 0x1e5c47b0, 0x25615c4d,
-    0x387a9a55, 0x26b8dc1e, 0x0f74eebd, 0x31b462c0, 0x17734dcf, 0x008255c2, 0x0c5d235d, 0x354a3bc1,
-    0x04d77170, 0x2012d28e, 0x16558437, 0x06b30fe5, 0x042a5e0b, 0x301107fb, 0x2a253fb1, 0x2fce1c74,
-    0x312ae4d6, 0x0a280a6b, 0x0a3adaea, 0x0f6fd33b, 0x2712abab, 0x0494f17e, 0x25cff08b, 0x308e83a5,
-    0x02e62390, 0x18c48ffe, 0x14d0c975, 0x0917fea5, 0x10b53a19, 0x1bd491a2, 0x02a4318e, 0x2c41a021,
-    0x24055964, 0x1b636bc4, 0x24aa5558, 0x25a5d07d, 0x38a960a7, 0x0dc06e99, 0x2a8157c1, 0x31908d0a,
-    0x26f7f7d5, 0x29263b6b, 0x14f56584, 0x322cfbbb, 0x251b5ca9, 0x079a3f16, 0x218415e1, 0x0a4d1c4e,
-    0x196290f7, 0x20b79f13, 0x19fcd7ab, 0x256667ed, 0x252e3912, 0x217b0c92, 0x32c12501, 0x18c7d5dd,
-    0x01c8e8ea, 0x144034f6, 0x277c9fac, 0x3ade79c2, 0x0d3db237, 0x20608d58, 0x01a3d350, 0x1bc310b6,
-    0x04ad6913, 0x24ddc690, 0x27804c62, 0x2a576d68, 0x20961c62, 0x1785f5af, 0x0919c1f7, 0x2aaab872,
-    0x25298e32, 0x17f572bd, 0x1b213eac, 0x3769e230, 0x078ec0d1, 0x0e5f795a, 0x235af6c1, 0x26bf3601,
-    0x1950a32f, 0x106ce8ee, 0x1ccaeb19, 0x2dfe0bf7, 0x24a926ef, 0x296a2c9d, 0x36143ef2, 0x0ece8b29,
-    0x10ebfd5d, 0x265677d1, 0x22963256, 0x0ceea2be, 0x05931df1, 0x13a4baa6, 0x194ccf55, 0x295923d3,
-    0x37aa5c1c, 0x27721944, 0x3aaa3b11, 0x0d87395b, 0x3b281775, 0x1ec79da6, 0x27944fe8, 0x0aed1b88,
-    0x2baa8861, 0x31511bec, 0x36e8a6c7, 0x08102dfb, 0x145e8dd0, 0x36e692a1, 0x35526df0, 0x3b53bff2,
-    0x0e404e06, 0x3a5eddaa, 0x20e5bc9f, 0x25d9c806, 0x2e7874af, 0x1fddbf3c, 0x390e6bdf, 0x03fa6ffc,
-    0x19305cbb, 0x39499f40, 0x1ba4c40f, 0x217cefc2, 0x04b55b56, 0x0a473be2, 0x393b9073, 0x03a16ed7,
-    0x10e3ac9a, 0x38d74d5b, 0x33d40368, 0x2410bcb7, 0x04568ec1, 0x36c70f77, 0x3777a9fb, 0x2d69e2fc,
-    0x03edad09, 0x2878abdf, 0x18591d3c, 0x01408aae, 0x117ebe78, 0x36a4482f, 0x212f7a54, 0x18969f57,
-    0x10552c72, 0x06b31eae, 0x0da44e39, 0x2ebfe5a6, 0x2628f6fa, 0x12836094, 0x0710ad5c, 0x1c8547e3,
-    0x05796d19, 0x2f3f231e, 0x39744915, 0x0d3c2641, 0x247504f5, 0x045a2813, 0x2e3fd005, 0x038b6917,
-    0x10c3d342, 0x38962acf, 0x29c422c4, 0x1aa4b107, 0x00f8bd49, 0x1a2c6e01, 0x3403238e, 0x306091eb,
-    0x2a03051b, 0x2a362d38, 0x19506689, 0x00f48d55, 0x16519cce, 0x1b7fbc62, 0x0dacc764, 0x0c46f5a0,
-    0x1e549b24, 0x21ab639c, 0x12889479, 0x06f47219, 0x07de9790, 0x0eb65056, 0x299066e9, 0x09bd6905,
-    0x2f235f82, 0x126b2ad4, 0x20873e60, 0x30e01f3a, 0x0765d52a, 0x0a8d4616, 0x1dc227a6, 0x13c10b60,
-    0x103ad788, 0x0a0c7c49, 0x109f6fb9, 0x2b3705c9, 0x22e9d947, 0x29086c6b, 0x3abf3f50, 0x23f7430d,
-    0x104c165d, 0x09ccc745, 0x17207ff0, 0x00f43af5, 0x0b8963e4, 0x0761937f, 0x059cf6f9, 0x3a349dbf,
-    0x073ab1d1, 0x14364ead, 0x09d467c9, 0x22e85782, 0x1d072540, 0x2a964fca, 0x215fcceb, 0x1457e5bb,
-    0x05cecb82, 0x1f78e9b4, 0x21011fee, 0x0ba9e262, 0x16ad0b21, 0x2ff75e87, 0x23db7d26, 0x20268f9f,
-    0x10f40e00, 0x08a42a4e, 0x3aa01b61, 0x1cb490b3, 0x1d03487b, 0x16fbd245, 0x3b597cf3, 0x06a1eaae,
-    0x010d7b28, 0x07911035, 0x2cb03889, 0x0edf404a, 0x029326ca, 0x16532961, 0x218dca8b, 0x06ab6edc,
-    0x01b66ef4, 0x25db13c3, 0x2cbb2b7c, 0x2d457ff1, 0x071ad25e, 0x1a79c1a5, 0x30428d87, 0x36dd1eda,
-    0x27ae4d93, 0x1efdb344, 0x2af90cb7, 0x2147a7a5, 0x214a0544, 0x0d3e3a79, 0x32c8893d, 0x22c34b33,
-    0x35ff8fcb, 0x2cec226a, 0x1cdeccec, 0x0573e68a, 0x2bef2d04, 0x18fae572, 0x333f9a2d, 0x139670c6,
-    0x1d9b5fae, 0x1fd623cb, 0x01fbfbe8, 0x1fac9f0b, 0x18ec01bc, 0x1f0982ca, 0x2ce6a0ce, 0x296677b5,
-    0x301b216d, 0x33c52f34, 0x33a97b13, 0x0366ebc1, 0x0558b476, 0x1e46efbc, 0x2d6bbeb9, 0x329ef9d9,
-    0x0424d588, 0x33443970, 0x33a2c1a4, 0x173a71a7, 0x2cab713d, 0x2d69bea2, 0x0d4ba987, 0x312e30d9,
-    0x0157b2c0, 0x1fb4bac4, 0x2625664b, 0x2963b5d3, 0x20809421, 0x09951a33, 0x0de72d8d, 0x25067535,
-    0x02a25b8a, 0x008b65cd, 0x191d1791, 0x2b2efd0d, 0x0573758d, 0x2af23e6d, 0x34a22a7c, 0x0627a762,
-    0x1118d9a9, 0x08e2d541, 0x05e037c4, 0x21ba9396, 0x2e5ae8a2, 0x2bc764b4, 0x04cd2d5d, 0x094ab40f,
-    0x1bd36f05, 0x1da56d63, 0x34832dc4, 0x3a267529, 0x0b63a647, 0x1e60cd1c, 0x353c789e, 0x133945f2,
-    0x2b83ed45, 0x2ef03d8d, 0x04d0e1cf, 0x04ba220e, 0x00989c76, 0x29efb6b6, 0x234486f5, 0x0995a1cb,
-    0x1c05327f, 0x00040046, 0x23751270, 0x281cc0fc, 0x0842921f, 0x01aab843, 0x1184df86, 0x357e14b9,
-    0x094ecc46, 0x3395cc9e, 0x1f38c90e, 0x0b562916, 0x125836eb, 0x1ba265ea, 0x1592e9e2, 0x18217a6c,
-    0x1ae69064, 0x2f6e42dd, 0x1f944e27, 0x1435a5c6, 0x0712ceb9, 0x049505ee, 0x1527fca9, 0x16a85748,
-    0x2a8d168e, 0x2b99fbed, 0x2a6e20c8, 0x1a1e6a44, 0x17da2818, 0x308c4ff2, 0x2ed3987a, 0x1d1f8058,
-    0x2dc4819c, 0x0ba41574, 0x206e5529, 0x2d54e023, 0x24bf1747, 0x04aefc3c, 0x0fb255a3, 0x26b15553,
-    0x293c14c6, 0x2b27f9cf, 0x33188a6c, 0x154cd6f3, 0x0d55bb48, 0x31e0132a, 0x0d1a584e, 0x29e95547,
-    0x3b0bfa74, 0x1b4cc3c9, 0x13d40672, 0x208aadb1, 0x1fb59d12, 0x244ea991, 0x34ee1c5f, 0x0bb1379a,
-    0x14d3ef3b, 0x2816a3e7, 0x32ed98b4, 0x3a7b2612, 0x0f3cac03, 0x065fc163, 0x174bfcd3, 0x1ac9049e,
-    0x1646ac18, 0x381dd4bc, 0x13e6560f, 0x025cd0b4, 0x3926d21c, 0x3a32ae3a, 0x266fb185, 0x0d123f8a,
-    0x0fb49236, 0x26243ff6, 0x24aeb3ac, 0x3b0e34cf, 0x062cbae6, 0x19008176, 0x322d55ce, 0x1f7293e9,
-    0x2b7281a3, 0x2db822fb, 0x18f076fd, 0x01425cf7, 0x3433bc92, 0x2ca01d3b, 0x13111550, 0x14afd4e9,
-    0x19bc51fd, 0x350be883, 0x1107263d, 0x10b8d632, 0x3738fc3d, 0x15818da9, 0x35b9f6f6, 0x363069a4,
-    0x1f2985cd, 0x13644a91, 0x2e3cbe2b, 0x08ef0eaa, 0x1a61497d, 0x2a519e89, 0x23120d3f, 0x2dff1041,
-    0x2ae64e5a, 0x3755986c, 0x2988a7a4, 0x1798f721, 0x15ffa13c, 0x0d24e4e7, 0x23b83d85, 0x16d131dd,
-    0x0d47bb69, 0x18903ac2, 0x13f79b40, 0x32668dbc, 0x009c6a4d, 0x0565a4a2, 0x0d89d5eb, 0x0b95b7d8,
-    0x35a93593, 0x26bf06ac, 0x39ce76ab, 0x04c371b8, 0x29dee296, 0x3adad16f, 0x0b4d38bf, 0x0f1347ea,
-    0x0bed9190, 0x2ba64a9d, 0x04cc64d3, 0x3817501b, 0x3b7f366d, 0x34ff1bb8, 0x30b7241e, 0x2af8e2e8,
-    0x22094829, 0x07e9486b, 0x0a1cb6e6, 0x07a781e3, 0x14b0cecc, 0x07f30f52, 0x30e4f631, 0x2bdd9c41,
-    0x2688aeb6, 0x33653f10, 0x222f4737, 0x001ab3d2, 0x09d210e2, 0x2e7379d6, 0x01ba54b6, 0x0d646881,
-    0x01ecbecc, 0x17662cd9, 0x299d163c, 0x2d29ca7a, 0x08618421, 0x23c7c6c5, 0x304ef8a1, 0x1be662c2,
-    0x3ae5256b, 0x0f63fc23, 0x3655b0ef, 0x1985b357, 0x2f7417f0, 0x12fc7aa3, 0x371e40c9, 0x31aa3b50,
-    0x129b77b3, 0x2923a401, 0x0d3f0879, 0x2843987e, 0x025d3da1, 0x18a33a90, 0x0e7e1044, 0x1023b8e4,
-    0x228286fa, 0x33539436, 0x00bc4d50, 0x13e466e0, 0x2294d35a, 0x1976b013, 0x1468dfd0, 0x02bcd932,
-    0x0ac624e9, 0x0c1a288a, 0x2b317bb6, 0x266b7ea8, 0x25a9db82, 0x149d7d88, 0x2abc9433, 0x004579ef,
-    0x305eba48, 0x2d139e1f, 0x10f9caf6, 0x30c2901e, 0x3a5ac583, 0x194824ed, 0x1ad30817, 0x12133837,
-    0x24d73cf0, 0x073b12d0, 0x1e9b0506, 0x131ffd65, 0x15823531, 0x30f3a6e1, 0x2e337a14, 0x274406ea,
-    0x1583841b, 0x06cfce66, 0x1d6dd22f, 0x34a8f727, 0x2c49feae, 0x33e6be81, 0x04c8839a, 0x32a56a8f,
-    0x08329516, 0x0182c6d1, 0x341eb096, 0x0fc18542, 0x155ced9f, 0x3043307c, 0x25cdfddc, 0x00731eb5,
-    0x2536babb, 0x0b1a2ef7, 0x0dc2e4ea, 0x291ead1a, 0x00d75caa, 0x0d838191, 0x12d9c8d8, 0x1aec3db0,
-    0x0e14e036, 0x06efcfdc, 0x176a64d0, 0x12f5419b, 0x2bd6792e, 0x1545b689, 0x281755f1, 0x0d7ec961,
-    0x149e7762, 0x32c74974, 0x1c137237, 0x0ba81ba1, 0x11206c6e, 0x24501aad, 0x0d475088, 0x2c845317,
-    0x0fa4bd62, 0x29d57289, 0x01b11d19, 0x2a304ed7, 0x11016e6a, 0x02939450, 0x2e089d9b, 0x07eda1b1,
-    0x10ecb097, 0x06fd1f46, 0x29077fb4, 0x1a85b7a5, 0x11619433, 0x06689382, 0x0ad19da4, 0x36d3bce4,
-    0x274ac816, 0x0d4da864, 0x215bccb3, 0x3551a619, 0x36dc7f93, 0x2dcc8509, 0x14ce75a1, 0x388592a9,
-    0x1a52d846, 0x2d6c510f, 0x388dde15, 0x166a14fa, 0x0b28446f, 0x214f2517, 0x2cf87aba, 0x35f6ea97,
-    0x2095a514, 0x30bca067, 0x0d44afc2, 0x06e6513d, 0x09c28215, 0x378f5bcd, 0x1d3f3e1a, 0x25d17da0,
-    0x2bf0e0e2, 0x186b7359, 0x0cb6da9e, 0x283b166f, 0x0c87d98e, 0x1376fedf, 0x04650637, 0x22eeb9f6,
-    0x06350427, 0x3771b3b7, 0x25505598, 0x1687a5c8, 0x303d5f9a, 0x0eb0e18e, 0x043b7ca5, 0x3619ebcf,
-    0x0db028cc, 0x2d5fd65f, 0x2798779b, 0x1506f5d1, 0x23f031b3, 0x20eac61c, 0x15acdf97, 0x3b67b603,
-    0x0c6f5b63, 0x21d45661, 0x0606ff4c, 0x0ae1e557, 0x047de3d9, 0x21fafa7a, 0x26091dc3, 0x004b5a72,
-    0x1f829c02, 0x079aec72, 0x1cdc0023, 0x25379674, 0x05c59e3e, 0x17d22ab8, 0x1e312a5e, 0x3728ab71,
-    0x1791b78b, 0x356f93b7, 0x25ce1727, 0x175e1d27, 0x047b12c5, 0x09f27508, 0x320de51b, 0x16efb15f,
-    0x21c03a6b, 0x08cb504b, 0x01f92d99, 0x21f44674, 0x2afca5a1, 0x1718a0e3, 0x25b47670, 0x1a73049c,
-    0x0fde410c, 0x27878011, 0x2fc925fe, 0x2ee99310, 0x1f971252, 0x03c29983, 0x0e838903, 0x15732e41,
-    0x03b4930f, 0x10c94a89, 0x24f0c0bb, 0x0aba639a, 0x2e6fde51, 0x1719a6e6, 0x1cba5e88, 0x18c21aa8,
-    0x0d5177e9, 0x0f581649, 0x13eb4fd5, 0x3270348d, 0x3230e5bf, 0x0c8787c8, 0x2a4b2406, 0x24c7b2d9,
-    0x3902c43b, 0x246ad4f0, 0x09b53ccb, 0x2a2e5868, 0x1f535c68, 0x00db62b1, 0x0a4778f5, 0x223478d6,
-    0x044851c1, 0x064b1a2e, 0x317157e6, 0x1ac0a8d5, 0x0b0e2380, 0x15f5d074, 0x3b3cf65f, 0x3202303a,
-    0x2d29aa6e, 0x01fb8ac5, 0x2bbb39ac, 0x23e63bf8, 0x20c88c0f, 0x1718a9b9, 0x181fd64d, 0x3892ab65,
-    0x312b4dcd, 0x31fdab45, 0x0fb54b44, 0x31721a82, 0x254844c7, 0x1402de44, 0x2caa2a03, 0x3488b12f,
-    0x300ba826, 0x397be27c, 0x0b1be3ec, 0x11175fc3, 0x297ae6a9, 0x078f275c, 0x16f03d2b, 0x348768cf,
-    0x2885fb1a, 0x005cf8b7, 0x1da13b76, 0x29e0b9a9, 0x31374a69, 0x37a29563, 0x2f097a13, 0x15740df6,
-    0x2685a069, 0x2a4a8adf, 0x24d0e56c, 0x105c30d0, 0x0da806ad, 0x3826c98f, 0x24a7239b, 0x249c81bc,
-    0x3386d414, 0x0ed11c24, 0x0c5bf96d, 0x2fcb9f86, 0x1492c48d, 0x040a50c5, 0x0692ff6c, 0x3771b4ea,
-    0x13ecea10, 0x0b1d80a0, 0x1f1c2606, 0x2837f413, 0x095fb676, 0x37aac920, 0x1f4d14ef, 0x16a272ba,
-    0x0953ed5f, 0x35e63468, 0x25166d44, 0x274c48fc, 0x29f0062a, 0x1b25e6ae, 0x2b3a3997, 0x2ab6a77f,
-    0x17995e57, 0x087618ad, 0x23c0a097, 0x06b11fbc, 0x04223be2, 0x0a1d582a, 0x1ea93ffc, 0x0474ddd7,
-    0x023d814b, 0x1a62dd70, 0x14c12895, 0x301e0c65, 0x1dd627e4, 0x0725b5af, 0x2c3bcab8, 0x26f2a4f3,
-    0x383b366f, 0x03a9a5c9, 0x0fa6c261, 0x3aba1723, 0x121779d0, 0x30848c9d, 0x3a1634b1, 0x1226bdee,
-    0x19f13811, 0x1e5f678d, 0x38820d2b, 0x36021a86, 0x21d35ea1, 0x0b1cb2b4, 0x08e6c577, 0x0808525d,
-    0x322c43fa, 0x266a491d, 0x0d7c6f5e, 0x08f45623, 0x0a62b712, 0x1349375b, 0x32832e9a, 0x130a01da,
-    0x1122cf62, 0x23c32a17, 0x1f0d7afc, 0x35f1dfac, 0x241a45ad, 0x297b32bf, 0x32fb9272, 0x2217e545,
-    0x36390cc2, 0x09d7cae2, 0x25b09e1b, 0x2b575dd4, 0x1c7c38b8, 0x1183403f, 0x3a67707d, 0x17c53f8d,
-    0x202cf097, 0x2b8cf9e9, 0x0750e515, 0x14c053b2, 0x0389986a, 0x0af8a01b, 0x1a9df87c, 0x254902b9,
-    0x01c2edbb, 0x2099db4a, 0x2f5bfd0d, 0x1cb4bb0c, 0x359b2867, 0x02d99eca, 0x2e356243, 0x0809462d,
-    0x04d645a0, 0x257c7efa, 0x02200564, 0x1d9748e7, 0x1805e42a, 0x267c465a, 0x3a2566c5, 0x36fb1cdb,
-    0x04bb1412, 0x21694f19, 0x00badc74, 0x1ed86550, 0x0bbca5d7, 0x21399e2a, 0x0b87a378, 0x35fd6389,
-    0x154592a8, 0x1b78fb16, 0x3881b9e5, 0x02d88efd, 0x32463be7, 0x3515421f, 0x0206fe43, 0x1dfabb56,
-    0x1f6958e3, 0x1a15c27e, 0x326ac9ae, 0x1ff60ff7, 0x12acab00, 0x26596471, 0x312a59fc, 0x2747ebfb,
-    0x213e4568, 0x30b9c1ea, 0x17b2c6b9, 0x3a43d45e, 0x39c2a8fd, 0x30d2949d, 0x201d02f4, 0x2b0e6c58,
-    0x276773db, 0x041c27e5, 0x0c4fd1cc, 0x36ea3a4c, 0x2f4c4957, 0x19cac8e1, 0x0b6b253a, 0x0efd3ca1,
-    0x07dc515c, 0x3a6e5d1e, 0x1dfd7d04, 0x1d8efe1f, 0x31df67ef, 0x273be92c, 0x2093b4b3, 0x0f5848af,
-    0x08ba4a40, 0x3941917e, 0x1f590802, 0x2ad7af76, 0x3b3955f3, 0x32417ed6, 0x356f3d8f, 0x36ae0d40,
-    0x18232023, 0x09b1fc45, 0x32dd4b11, 0x0ccb7ff6, 0x3381d95b, 0x247e6087, 0x11308290, 0x09b34200,
-    0x3aa35f9b, 0x368139ae, 0x28289b8e, 0x1a21665b, 0x19d2bef4, 0x31d2daaa, 0x0e7ee40d, 0x2bb768bc,
-    0x281dd443, 0x30f04c16, 0x191367a9, 0x1d269e09, 0x2c876f75, 0x2dc0d5a7, 0x2f57eb2b, 0x0f747994,
-    0x2a88ad75, 0x164abc69, 0x3957bee4, 0x25adb5dd, 0x0bbb50b7, 0x19446722, 0x27070f51, 0x38de073d,
-    0x189a560c, 0x0a135377, 0x35a8c238, 0x02192657, 0x10a038e4, 0x1c634af1, 0x2dacb876, 0x0f18ae7a,
-    0x14dd2f27, 0x320b6efd, 0x33e4baf5, 0x336de97d, 0x3adc2f57, 0x06c79cd8, 0x26652344, 0x0dd116bc,
-    0x2ae76ce2, 0x13ab6159, 0x346137ce, 0x19b91e69, 0x32b476c0, 0x3b833410, 0x2d244c3e, 0x112e21f6,
-    0x20d04f9b, 0x2c573a1d, 0x2ad63b6d, 0x2a3947f3, 0x08e07d68, 0x0ba22a9a, 0x34d7d04b, 0x12bc9114,
-    0x293fa558, 0x09e54bd4, 0x0ce957a8, 0x2fbf1884, 0x03b2d67a, 0x1e2230de, 0x1141a1c2, 0x2fbfbfa9,
-    0x123d5972, 0x034e4662, 0x0d1d0c0c, 0x1c2c11cb, 0x2c2ed808, 0x04bf3c22, 0x1d49c81a, 0x17049575,
-    0x0c59ebb5, 0x17f5232e, 0x07054a0f, 0x0e6ecb4f, 0x1f02aa40, 0x00605493, 0x0265aaf6, 0x3471a784,
-    0x0c9ac93a, 0x19a590d1, 0x370c96e4, 0x0b878653, 0x049e8101, 0x143980d4, 0x14270da1, 0x35b3d921,
-    0x10eeb744, 0x0c0e69bb, 0x24ddce37, 0x08cb6dd3, 0x131f173c, 0x01261258, 0x0acc99e9, 0x2da5a18d,
-    0x2479da30, 0x096ea0e8, 0x25d678ec, 0x2f4ba970, 0x01350660, 0x29465430, 0x17d8f6da, 0x30cab80e,
-    0x095733f9, 0x212bfa00, 0x27a5618d, 0x31e54c0b, 0x10d35cfc, 0x1c7bfca8, 0x21cba267, 0x18781549,
-    0x2fcb020c, 0x1809b450, 0x220643a4, 0x03d6153f, 0x23198819, 0x0c8c165d, 0x190f042d, 0x1ab55366,
-    0x34b7800c, 0x033f436d, 0x395c4c2d, 0x01ff013d, 0x1a84fb31, 0x16ab8530, 0x236d72d4, 0x0d7b28df,
-    0x116f78d6, 0x0d5eda89, 0x0a6b8eff, 0x12e71987, 0x06659eac, 0x1c72befb, 0x224f0077, 0x3a760222,
-    0x37140140, 0x1b193ab1, 0x1336d3e0, 0x0f5b63d2, 0x00e1fcd6, 0x0d0cb021, 0x36cf8b5c, 0x0264c141,
-    0x17590896, 0x26a7709e, 0x1e56ff11, 0x2d739b1b, 0x1a8c1662, 0x142e575c, 0x0008c378, 0x3187e35e,
-    0x1af7b5c6, 0x2f0dfa8c, 0x398c4c91, 0x088002bc, 0x044166c1, 0x29b951aa, 0x27b33210, 0x2fa60a50,
-    0x11555906, 0x397c9d06, 0x151cfb94, 0x1a4008ce, 0x367e9f83, 0x2fcaac28, 0x06d95893, 0x34ce37ff,
-    0x1d771b2d, 0x162d9804, 0x306ad35b, 0x35faf30c, 0x27b0966c, 0x06debb47, 0x10d8b00b, 0x0d55cf36,
-    0x18b83760, 0x227d02d1, 0x258dfcfc, 0x065acd7e, 0x3902f874, 0x1b0f8593, 0x33b43542, 0x3b1b991b,
-    0x00143811, 0x21793bea, 0x32f5757a, 0x3ad1d7b8, 0x30196d90, 0x3b7428a4, 0x358f7380, 0x026dac71,
-    0x1c467c20, 0x2469a5f5, 0x39aeb0c7, 0x28102ca5, 0x03104990, 0x31a1c55a, 0x0231e076, 0x1e9c7810,
-    0x38e17d0f, 0x3a1944d5, 0x1eb53f0d, 0x3725b934, 0x27d8c3e2, 0x1a395e9b, 0x32cbd562, 0x2f40d1df,
-    0x2047ee5c, 0x30cfa754, 0x07344c1f, 0x21350b96, 0x18cc5237, 0x1c58d79f, 0x167c4807, 0x2f4ad613,
-    0x1d2e9ff9, 0x1f9f6e63, 0x2d7d8606, 0x141aca67, 0x0535e38b, 0x218007c7, 0x35768d5c, 0x244ff781,
-    0x148cd6f5, 0x14c63632, 0x19c9e040, 0x2b732707, 0x1b85af9a, 0x0f2a0403, 0x12b5d1c7, 0x141a2b0c,
-    0x09fe6b75, 0x17e38c7d, 0x0670cd56, 0x0ea56dc8, 0x26a916a9, 0x30adf78c, 0x11c5ef71, 0x13c123f3,
-    0x0e823d9c, 0x2a42a82d, 0x17147024, 0x28fb5490, 0x18d2cc9d, 0x0fe28792, 0x31f364ec, 0x32c21bb0,
-    0x0628fcd0, 0x1e8302e0, 0x21f51815, 0x1123992e, 0x05dad8c4, 0x0dc84344, 0x09af180c, 0x0df0600a,
-    0x3094975a, 0x16eac94f, 0x3a5c7b26, 0x1527ee20, 0x36dbe4a4, 0x004056bf, 0x2cbe5174, 0x054824b6,
-    0x0c6b3860, 0x0e7ef87e, 0x1b597ca9, 0x24b7c57d, 0x199d5995, 0x170d64f0, 0x0fcd4085, 0x0d31fa5d,
-    0x0bfa4b65, 0x32b56810, 0x1dcfc37f, 0x06148939, 0x0445f3bf, 0x2f805a39, 0x292bf529, 0x14318760,
-    0x1d2d7a5b, 0x13bf0e78, 0x1e44b511, 0x13df8483, 0x1c8dbfdb, 0x20ab4d33, 0x08e218b8, 0x0cc3fd18,
-    0x2cae3dd2, 0x0ddfbe6d, 0x08eb5c25, 0x0a428f81, 0x2ac83272, 0x0dcbd14d, 0x0adb3c5c, 0x15c3a9bb,
-    0x17be0ce2, 0x24dd33f9, 0x1f106c2e, 0x1419e18e, 0x27335b90, 0x351a9e28, 0x206e31ff, 0x05bc4a6d,
-    0x1fe0325b, 0x3821d59d, 0x18d6d966, 0x34ba54d2, 0x338a31ca, 0x21ee436e, 0x1aed2ccb, 0x1642755d,
-    0x1c6acdea, 0x2ee8d413, 0x3a1af795, 0x1072ed8d, 0x2aab7cb7, 0x1d9f994e, 0x23bb4664, 0x01c49e5b,
-    0x315fdd71, 0x34e6c5d5, 0x06b708a3, 0x0444f3c1, 0x0ae7d28f, 0x1afd0776, 0x361945f3, 0x09fc9683,
-    0x39c1f6db, 0x378c7926, 0x1187759d, 0x28c7d5c5, 0x18733db8, 0x355012bb, 0x34d19609, 0x2d1bf987,
-    0x1ac31337, 0x1e85d8ee, 0x37f5701c, 0x32f98f6e, 0x1ca4c322, 0x2ac75343, 0x2a4d6d75, 0x0ee19980,
-    0x0e8feaf0, 0x0f5cff03, 0x0f61745b, 0x1afe75dd, 0x30c60f15, 0x1aae8380, 0x072e2e7a, 0x0dbce9c5,
-    0x2a37aeee, 0x223b7961, 0x2f6da3e2, 0x12f959d6, 0x1b97a025, 0x07cea3d9, 0x29ab3c12, 0x1e8b73ec,
-    0x1be28a02, 0x0cb4b425, 0x28703d58, 0x090e750e, 0x1faabc28, 0x3b495636, 0x249c953b, 0x295445b9,
-    0x0e0d9428, 0x0fcc4d95, 0x31d1e192, 0x2b364616, 0x01589dee, 0x022a7017, 0x1e6c5029, 0x259b5dcd,
-    0x1c4b3c38, 0x0ebabd33, 0x235ce43e, 0x33377d97, 0x1b16745a, 0x269cc4c4, 0x20de12c9, 0x0c8f8896,
-    0x09363a70, 0x1917fba5, 0x217f0949, 0x03948be3, 0x3282fbd6, 0x0e60fc67, 0x2f3d50c8, 0x2124c22d,
-    0x158737c8, 0x112a117b, 0x148f8aff, 0x316c57b7, 0x3428d649, 0x2ac129f6, 0x0e870e23, 0x153df153,
-    0x28b13c82, 0x122823c6, 0x2ea76221, 0x36af16e7, 0x18ca9638, 0x2a375685, 0x2e1f9fa7, 0x00aa2ae7,
-    0x125f4b83, 0x145d7c9d, 0x1c6faaf7, 0x27465284, 0x036021e0, 0x096824fd, 0x031f5013, 0x0120ac6f,
-    0x2d7d64aa, 0x193ecbc2, 0x0cf0245f, 0x22a7e892, 0x27dbc82d, 0x04aa574d, 0x23b28783, 0x1ca1760a,
-    0x3af3f231, 0x15b3d351, 0x2c5ed61b, 0x07caf43c, 0x3797dca9, 0x11581d0c, 0x21b5a196, 0x3570442d,
-    0x15b4cfb1, 0x09af3834, 0x382695f2, 0x31f6bd2c, 0x18e5ce9e, 0x1058f4e1, 0x065426be, 0x05876fb7,
-    0x24cebe45, 0x37d9f910, 0x05df072a, 0x2badd4b3, 0x1e80c02d, 0x29de6f56, 0x0c765cb0, 0x335b7788,
-    0x1e69af3e, 0x17378b8d, 0x39ee2c61, 0x17e87fde, 0x3af3dcd1, 0x07fefc32, 0x134ba59d, 0x2c68955f,
-    0x11794c41, 0x35a57ed2, 0x386faf04, 0x142b3f31, 0x0b73121d, 0x101fbc14, 0x3b61019f, 0x116f39f6,
-    0x1c69dae0, 0x0aab313b, 0x30c3b2bc, 0x0b1db7d0, 0x092833a5, 0x38c580f6, 0x2d0544f6, 0x27de0fe3,
-    0x20832bca, 0x0c91644c, 0x1efce6b6, 0x11b6e4f6, 0x0ed17384, 0x204d77d3, 0x12f2d9a4, 0x249173b3,
-    0x21ab5510, 0x26243c29, 0x31f32e34, 0x1c5d7757, 0x350734c9, 0x281c64e9, 0x1593d57b, 0x2a034570,
-    0x306a90bc, 0x37009c7a, 0x0c5f9e45, 0x3b29d763, 0x339336ac, 0x0eb59ce0, 0x20538979, 0x1313afa8,
-    0x07635c7b, 0x042168c2, 0x1798d27d, 0x1a8888fd, 0x257a27e7, 0x36ff7020, 0x2b96e856, 0x30a908a2,
-    0x022d1a83, 0x28726319, 0x17e02477, 0x279702c9, 0x322381ca, 0x2120493a, 0x1e77c730, 0x2274cb08,
-    0x16bbcdf0, 0x02dfc546, 0x16b4ccb4, 0x264be909, 0x12bba093, 0x0eef0ab3, 0x0d94c3a3, 0x31128191,
-    0x2b0f390c, 0x2678af00, 0x0243cc5f, 0x17875ecb, 0x31655f28, 0x377955f6, 0x2d9d5804, 0x21f7fac1,
-    0x059ab9e5, 0x15c72828, 0x28d54e64, 0x0162fcc8, 0x303be6e2, 0x397d8371, 0x396f6ad1, 0x06dc7914,
-    0x290186ce, 0x020a035a, 0x25da6417, 0x3820a300, 0x1e60096d, 0x1963a53a, 0x10699910, 0x1139e3d7,
-    0x14d459fd, 0x39705a9a, 0x37843f5a, 0x1da15477, 0x320a34ce, 0x2259abed, 0x33137fd8, 0x23c103e8,
-    0x32463d17, 0x1755aa83, 0x1e7cdbf3, 0x0c44af33, 0x35bef3ba, 0x2f6348c9, 0x0e9c35e6, 0x33c5b3ad,
-    0x1ca666c1, 0x3b400b20, 0x29ba7530, 0x0b6a4f7b, 0x1cab035d, 0x32462bcb, 0x3a0014ff, 0x086946f7,
-    0x0da9933b, 0x21e22e60, 0x17eacefc, 0x0311f77f, 0x1678c0ff, 0x1bbb70a0, 0x0d13d3a2, 0x0f9d0508,
-    0x09a3c1e5, 0x19a259c7, 0x2cb18f7e, 0x0c6fc155, 0x03ca8296, 0x1a078413, 0x2836b7db, 0x0cb6013d,
-    0x1ddc98cf, 0x1b9ad094, 0x1c1dc222, 0x05276ba5, 0x21e81b8e, 0x289472b8, 0x244ab5a7, 0x287d1762,
-    0x271fab55, 0x1a1d36ac, 0x2002c06a, 0x26815d76, 0x1ab6ac14, 0x0f87bf9d, 0x0ed21e65, 0x2d3ae3f9,
-    0x37d09f11, 0x051287a3, 0x32fef52a, 0x239485a5, 0x231ba68f, 0x15060612, 0x27e8b2dd, 0x30cd55c2,
-    0x0c8e4f80, 0x2ffac779, 0x19ed7f66, 0x2a6a58e6, 0x1eea5d0f, 0x04c66a8a, 0x15d0f41a, 0x26ed001e,
-    0x33bd9044, 0x28d71800, 0x398b5ce1, 0x21d66248, 0x0e0bad66, 0x02c21f87, 0x18126c21, 0x14eede33,
-    0x3b7ea863, 0x1e5d6f50, 0x389e4b36, 0x022b48a6, 0x2d270e22, 0x1a84524e, 0x24e7e6e0, 0x1c6de39d,
-    0x1f4a3bcf, 0x392530a6, 0x3a30a46b, 0x0f510b1c, 0x310beb63, 0x3611bd2a, 0x32627119, 0x1d0ec1f3,
-    0x010bdfb8, 0x37cfd89a, 0x222a621a, 0x2048b198, 0x222b2d62, 0x3b6bd200, 0x277a7cfb, 0x2f7c7267,
-    0x368e84d4, 0x37e2ae91, 0x340914d6, 0x16a805ae, 0x01a0e3e1, 0x300450d3, 0x2aa83ed8, 0x3733f7f5,
-    0x2874563a, 0x25c0794a, 0x2c259be9, 0x18becad5, 0x087e774e, 0x2e59ff90, 0x2d90667a, 0x1964e0a9,
-    0x2e2af577, 0x28340400, 0x024daccf, 0x2d045687, 0x03507e36, 0x09530369, 0x051a02d4, 0x1b5ae5da,
-    0x156e54fe, 0x2e959ab6, 0x29047d40, 0x1b37ff54, 0x107ec149, 0x0b14526b, 0x2262c2fa, 0x100fd979,
-    0x0d7e4a8c, 0x0185478a, 0x14a5af2c, 0x188176be, 0x2b878d0c, 0x1a3a6600, 0x36a13fab, 0x2e0b666e,
-    0x386ed9db, 0x0ad39e9e, 0x38a79eb7, 0x0cd072ac, 0x347feed4, 0x23fcaee5, 0x05b5b335, 0x10e1e395,
-    0x07eb04e2, 0x2f62b89d, 0x01e0fd5d, 0x101ef382, 0x31f74b53, 0x37371f1e, 0x20ddfb98, 0x2f96a629,
-    0x1609f6f5, 0x206f66d7, 0x25b491fd, 0x097f814d, 0x355df647, 0x363c4cca, 0x37276765, 0x248b339a,
-    0x02f45d14, 0x152385a7, 0x118fb536, 0x32fabe64, 0x303281e3, 0x35730e51, 0x1a07ba8f, 0x30fb5eae,
-    0x1c6b8963, 0x1893f274, 0x1c1862c1, 0x32cc3d35, 0x0370b424, 0x03b250e5, 0x2c53f5ca, 0x35eb2c6e,
-    0x007ce432, 0x2db5987e, 0x0156f1e3, 0x289eb77a, 0x37c499be, 0x07822687, 0x2dcd776b, 0x0adaf58c,
-    0x08d06469, 0x1c3d3932, 0x0c82975f, 0x21a085c6, 0x34ca59e2, 0x13100d15, 0x270f2077, 0x304b7ad9,
-    0x19df9284, 0x14e3f4b6, 0x1fbfad95, 0x2115907e, 0x274c4421, 0x24203c42, 0x23a74349, 0x09811584,
-    0x179f822f, 0x187e3987, 0x311aac03, 0x33553075, 0x0460b968, 0x32d871a4, 0x1d5fc587, 0x38932dd2,
-    0x36315404, 0x312c143c, 0x32a855ec, 0x1e94a74a, 0x1bf8a8c3, 0x2e89fb12, 0x34b933ce, 0x189f8552,
-    0x0a39bc4d, 0x0b6942c8, 0x24772fcc, 0x02187fa9, 0x1d5288ff, 0x2884808e, 0x314431e7, 0x27aa9997,
-    0x357efdf5, 0x07cc979b, 0x19c26c59, 0x33c23f24, 0x21c55530, 0x3492b7fb, 0x07e5896a, 0x1459f27f,
-    0x0a9bd12b, 0x1d9ec7df, 0x235ec938, 0x09803c1f, 0x0af2c692, 0x19e24bf7, 0x081772fc, 0x3042b282,
-    0x02b0be72, 0x1aa39d35, 0x08120176, 0x06179947, 0x04d7d846, 0x0c68030b, 0x02a5d3bc, 0x0d3309a5,
-    0x389f915f, 0x1f320a1f, 0x30a55598, 0x15b4214d, 0x2ed788e5, 0x1f8f073a, 0x0c817f8f, 0x2c6810f7,
-    0x23aee4b3, 0x368a4b3f, 0x307ba2f3, 0x07079ef8, 0x2beb788d, 0x21ad2299, 0x1db3c2c3, 0x1a040521,
-    0x20f15c64, 0x1b305872, 0x05bed111, 0x24a9d997, 0x29de1689, 0x2cc94927, 0x16855c84, 0x091c6c89,
-    0x0df82433, 0x053d238f, 0x2d858a15, 0x17309c5a, 0x015377ff, 0x197351bc, 0x383abd1a, 0x03cfd1a9,
-    0x09bb45fe, 0x19e7fe4d, 0x1a0d95c8, 0x27843d8c, 0x1b4114f7, 0x07222f42, 0x21cb52ba, 0x138aaad2,
-    0x34b454ca, 0x1d9d5d96, 0x29e5aa35, 0x01eaa9a2, 0x06675c66, 0x01f4f36e, 0x2be560a6, 0x017addf8,
-    0x35753c10, 0x0a8862ca, 0x14b6c0b4, 0x0c12285f, 0x29d11c9c, 0x1a11dbf5, 0x04d0d4ee, 0x2e490cf1,
-    0x0d924675, 0x096af226, 0x044946f9, 0x0035235c, 0x2355135d, 0x2bd87388, 0x2f467336, 0x261c871b,
-    0x26c094c0, 0x0edce4d7, 0x0baebc33, 0x01a9b8a6, 0x20a326dc, 0x29b89ece, 0x1b4c48da, 0x0d0f7108,
-    0x28bab09f, 0x08db683c, 0x1a2e3616, 0x1f3c91b4, 0x03b99ba1, 0x22189ee5, 0x3a71ab27, 0x08c6bf0b,
-    0x3a78287a, 0x21a61d24, 0x17f6883f, 0x1f479cca, 0x2aa328f9, 0x3ae8300c, 0x004b3221, 0x245dc1b0,
-    0x12f3c6c7, 0x15ace15b, 0x3792fdd9, 0x014d2eed, 0x32f5f05f, 0x2a65a4e6, 0x2594a68c, 0x37c01259,
-    0x3260ead2, 0x152193a1, 0x370daa78, 0x18e7b6f2, 0x39199da2, 0x03138466, 0x11f7728f, 0x1741ae47,
-    0x1d084c91, 0x36583b3d, 0x3126e58a, 0x11ad8a18, 0x12e8c728, 0x20ade7db, 0x1887f027, 0x0ef3e486,
-    0x1434feb0, 0x107a90bf, 0x3b66146e, 0x18d577ce, 0x0ce3ca06, 0x0e73c6bc, 0x31465a92, 0x27cebb95,
-    0x09e843fb, 0x1c464c1c, 0x025d1141, 0x055304ee, 0x00914b5c, 0x127738bc, 0x1eaf1087, 0x2b1e6010,
-    0x18d05dc8, 0x0576674d, 0x112e5020, 0x1fbc981d, 0x19558dfe, 0x1a29b550, 0x245d5be5, 0x31a58351,
-    0x2c4fb8b6, 0x0e00e247, 0x018735de, 0x04aae2c8, 0x3254b646, 0x03a5d6a4, 0x2df6d5fd, 0x220ca597,
-    0x229d119a, 0x219158f8, 0x185b0aff, 0x21613c4b, 0x0b06c003, 0x0b2cc6ab, 0x0ab22e1b, 0x0fa249df,
-    0x1f9cdc9f, 0x2fcd4f94, 0x327dbd4b, 0x32cc4aba, 0x1cef8ba4, 0x354f51bc, 0x00c479a4, 0x338b54f3,
-    0x1e48220f, 0x04f01a79, 0x2b04dbf8, 0x0f2ce407, 0x28ccdffe, 0x0bc191e8, 0x050a2f53, 0x328afef9,
-    0x32b64161, 0x1ad164a4, 0x2f0c438b, 0x0c6324f1, 0x133f149b, 0x3203f900, 0x136fa4c4, 0x2a632edc,
-    0x20f2ff51, 0x3567e941, 0x19704b75, 0x210d403c, 0x1e3ecc13, 0x332f8a69, 0x1cb9fea4, 0x0a31b20c,
-    0x0dcebeb0, 0x289b6144, 0x18232d4c, 0x0a21c12c, 0x02584970, 0x118d27e4, 0x3235f9ae, 0x05c90ddc,
-    0x0e03a67c, 0x220a38f2, 0x30bfe82b, 0x35b29e8d, 0x04257c2b, 0x353d8cfd, 0x2256f055, 0x346a5c4e,
-    0x0702ab4c, 0x369af536, 0x32743ed4, 0x23e2fd45, 0x1c589984, 0x21459372, 0x344e8479, 0x1982f364,
-    0x0c7ee13e, 0x1cc1cab2, 0x02d20f31, 0x290de9e9, 0x31e564c3, 0x0abb61dc, 0x12f39a4e, 0x38abd918,
-    0x1b84897b, 0x2a113a39, 0x120a8834, 0x2f2f6988, 0x3aa1a9c6, 0x2eac4098, 0x15e08daa, 0x351f01f4,
-    0x34498384, 0x3732ee25, 0x238e0902, 0x02e06c98, 0x13497d6e, 0x0a012b6d, 0x1b98a179, 0x104a8bd3,
-    0x360964de, 0x064c6396, 0x2e650d51, 0x2013dde2, 0x15edad95, 0x0d49dd2c, 0x29caeaca, 0x237fab90,
-    0x2b6446d5, 0x053d4965, 0x043fefc7, 0x39b2f6e1, 0x259185b6, 0x026e6feb, 0x1f1dabe6, 0x39c08ea7,
-    0x3469a0b7, 0x2541177b, 0x34d37afd, 0x187da554, 0x17fd7833, 0x2e06baeb, 0x1fe46ea4, 0x27b6a6b2,
-    0x1e51bccc, 0x25e637d1, 0x29178613, 0x25713e75, 0x376320d5, 0x300d8f0f, 0x0e22e6d8, 0x03e23c65,
-    0x3489cf12, 0x0ec91e16, 0x223f2027, 0x1839a193, 0x141a39d4, 0x2f1d6d34, 0x2ffebaca, 0x19210447,
-    0x2cdd5123, 0x36c2b1a0, 0x10c60ccf, 0x2c6b38fe, 0x285b9a10, 0x05242ffa, 0x1c543e9e, 0x11547cc2,
-    0x0c73de8d, 0x2245ad49, 0x106d05ed, 0x3a3ce7a3, 0x19960090, 0x21f12288, 0x0064983c, 0x2c93aeb7,
-    0x0f0362ec, 0x235a22dc, 0x1bb74b16, 0x0a0d450f, 0x2d9a09a2, 0x284fdeca, 0x2c762b47, 0x1a21a416,
-    0x28f8d8f0, 0x21cab4d2, 0x1ca550ac, 0x0b98dba0, 0x04c438cd, 0x05ca6f0b, 0x0c3444c0, 0x0729a1eb,
-    0x1265830a, 0x2c51481d, 0x37cc9895, 0x36f0355b, 0x330aff33, 0x2a6bdbf2, 0x34814e6c, 0x11e12adf,
-    0x0d319571, 0x31833826, 0x260c23e4, 0x1370b4be, 0x2a5613b0, 0x0ad495ba, 0x369ae25c, 0x2afa0410,
-    0x0383642b, 0x2379a2ce, 0x0ffbe542, 0x2cfa06bd, 0x0643e9d8, 0x007b8a51, 0x2cccaff6, 0x1c7aacc1,
-    0x3b4cdfe8, 0x3b713469, 0x02e6f0b7, 0x0793f0d9, 0x1dc0b03d, 0x0ca0112c, 0x30c7403b, 0x087ef743,
-    0x159e5670, 0x224e4d64, 0x30bc9bd2, 0x24765543, 0x274fd8c8, 0x07d0d097, 0x190d7e16, 0x00c210e5,
-    0x351aee23, 0x27d83499, 0x21c19b78, 0x262f6b18, 0x32839b74, 0x2ff4fc1d, 0x0463c3af, 0x28015edc,
-    0x0416eb43, 0x000cc34c, 0x2b594b67, 0x3b4b2419, 0x3672ea7e, 0x30f5c97c, 0x08ce1e3c, 0x2e698540,
-    0x182323b7, 0x1c70cfa7, 0x242157f4, 0x0a23785a, 0x30734cf3, 0x01f59cce, 0x1d56d703, 0x12741935,
-    0x027b2b76, 0x279c0941, 0x37faec84, 0x12785fa8, 0x2df51363, 0x26b26b6f, 0x0e1365ce, 0x3ac74ca8,
-    0x3175051a, 0x10e42d47, 0x192efb54, 0x0caca0ee, 0x169b5def, 0x026c5def, 0x197a21b2, 0x1ca64650,
-    0x2a5a356c, 0x059c550d, 0x09146741, 0x03445cf0, 0x14164fb8, 0x22ea881c, 0x2ea1a2e3, 0x044dbd2d,
-    0x1a07663e, 0x15f6c9ee, 0x25070786, 0x22b55fca, 0x36f267c9, 0x0ab119ce, 0x01448eb3, 0x087c572c,
-    0x1f18adbb, 0x26b7b1d6, 0x0471b78e, 0x3780fd1b, 0x223a9479, 0x05b81872, 0x2fb4be53, 0x062d7f22,
-    0x39fd9c80, 0x2369b3be, 0x1985424a, 0x26ffb3e7, 0x003c6664, 0x10a1a00b, 0x1f0c06ba, 0x0717100c,
-    0x27c4addb, 0x01a07646, 0x35df1e0b, 0x273ffd37, 0x35b01346, 0x2d03f64e, 0x0dc0a6bd, 0x0da147a6,
-    0x0a32eaf0, 0x09d109e3, 0x1019abb0, 0x38521ae7, 0x34a944a7, 0x1520859e, 0x171a57a6, 0x133d40fb,
-    0x13c21c09, 0x00933130, 0x15b8ab00, 0x1049c247, 0x2177e42e, 0x247893dc, 0x00914b7f, 0x2fb6f377,
-    0x226599ed, 0x09b88f1a, 0x1b675331, 0x3862fb56, 0x2aaa7420, 0x39e4a362, 0x34897c36, 0x0d7c5b7b,
-    0x391fea86, 0x0d511f22, 0x1a80f0b8, 0x30c16ca1, 0x26ab3ef7, 0x0e6d5384, 0x009ac106, 0x1de0a304,
-    0x15cd3ce2, 0x2f319762, 0x37940a36, 0x1710e76f, 0x1e0994ce, 0x06b02287, 0x16ccba05, 0x27dd27ea,
-    0x35684da8, 0x0afa59a1, 0x32385102, 0x04e8caee, 0x0e29c3c9, 0x0d79f9ef, 0x01022022, 0x126f6354,
-    0x0f662a64, 0x0573198d, 0x1c8baafb, 0x32b5ebdc, 0x32e30df5, 0x1877a0da, 0x33d56487, 0x3053d730,
-    0x0d37c18c, 0x023dd56b, 0x240f3e69, 0x1166c1da, 0x12c8d12e, 0x390a177c, 0x177966bc, 0x1dbbb82e,
-    0x2fc8817b, 0x1a901590, 0x117d5ddf, 0x1f789029, 0x38196ce5, 0x0489a634, 0x1cdd22f7, 0x1d6669f3,
-    0x3965ebc5, 0x227e547d, 0x3b70afd2, 0x14cfc74b, 0x007a9d64, 0x18508ae6, 0x2e4d7d25, 0x24000611,
-    0x169186b6, 0x2bbf0698, 0x29cef082, 0x34d68643, 0x2a6ab71d, 0x13dc0459, 0x1928fc79, 0x31be464a,
-    0x006f3c20, 0x3ae24058, 0x15b71813, 0x2a7583bb, 0x2ab5d0f6, 0x26a73e13, 0x3744502a, 0x2aeb7d0b,
-    0x1149a166, 0x38ff9c74, 0x10da7367, 0x3686bf5a, 0x0618409a, 0x0702781a, 0x02b6047c, 0x186b291e,
-    0x34e411af, 0x30d57606, 0x28f3c644, 0x1dbf2574, 0x2c9362c1, 0x1c40b597, 0x273ca627, 0x30a0ea26,
-    0x1cc57f1b, 0x16e8de90, 0x291eefaf, 0x0708dadb, 0x22fb166e, 0x36bd79ab, 0x06265923, 0x1c8c4334,
-    0x26511d2c, 0x2d0acafe, 0x0cb81f72, 0x15cc0ec9, 0x166c4ac5, 0x2677d269, 0x06bf94a1, 0x28ebeaba,
-    0x02850db4, 0x24509b8c, 0x21c438c6, 0x0251edc4, 0x34a53d16, 0x0d6509ab, 0x27c1313b, 0x333579e0,
-    0x0fa6e8da, 0x189cd596, 0x08a51bc7, 0x0414cd30, 0x079821b2, 0x0dd4d9ec, 0x108538c3, 0x02d60f74,
-    0x1cfd70d7, 0x29b70084, 0x0c1093c5, 0x01010a45, 0x39f59b44, 0x3375940a, 0x1bc4ead5, 0x376b7c38,
-    0x06e5b2e3, 0x0698c454, 0x2d8758eb, 0x043d9503, 0x13fc12c8, 0x0c7e13b3, 0x193041ea, 0x3952a4af,
-    0x241c5b7f, 0x1678fd7a, 0x258fa3bd, 0x3a59c1f8, 0x19a53625, 0x30efb633, 0x2cb807c9, 0x0aeca40f,
-    0x014427b9, 0x2a8d113e, 0x02dcf340, 0x2314220a, 0x148a8fbf, 0x103d7ae2, 0x1a2f2ad6, 0x0734e87e,
-    0x1e9bdabc, 0x2c37adec, 0x1e132b0a, 0x15672e8b, 0x04b59ba4, 0x34939a8d, 0x15ce3d27, 0x08b6faea,
-    0x24cb8776, 0x1ba2b4a4, 0x061cbebf, 0x35cc8f66, 0x1de49746, 0x1d925522, 0x1571cb3d, 0x1d8c29bd,
-    0x0cf48fd8, 0x146b6fdc, 0x08ac0787, 0x33bcf151, 0x01a6d56e, 0x03810a69, 0x31f6a3ea, 0x0e4076f8,
-    0x30f3ae38, 0x2d63f729, 0x1becabd6, 0x2a11cf0c, 0x1254a9f9, 0x2d664e49, 0x021b066e, 0x238471ac,
-    0x2e0db967, 0x364d18fc, 0x0419ff59, 0x16921391, 0x21493d2a, 0x05c7962b, 0x17c28768, 0x34c74c05,
-    0x082035aa, 0x1053ec15, 0x16b59f6b, 0x327bb16a, 0x2c1fac1b, 0x3821e519, 0x078dde3c, 0x012540d8,
-    0x0b107ea2, 0x2920e8dc, 0x36d502ec, 0x191f9adb, 0x3b50de4f, 0x311127fd, 0x0c78e8b1, 0x35fe9e76,
-    0x266854ae, 0x28270d5c, 0x1c27902e, 0x0b20de5e, 0x07686b22, 0x2a150407, 0x36e8246d, 0x012e8fec,
-    0x1d647f91, 0x28736cfb, 0x29973c5b, 0x3145e6d9, 0x0f058a9d, 0x37f293b9, 0x0859d3bc, 0x00a7f516,
-    0x2894c51e, 0x1d805eb9, 0x35eab19c, 0x145e383d, 0x27bd0555, 0x2e0251ef, 0x0a116dba, 0x000b27ae,
-    0x02e88122, 0x0d058e9f, 0x21226f35, 0x0ebef9de, 0x0d2a8626, 0x2ebe9a0c, 0x219ddedd, 0x22b91683,
-    0x25198067, 0x00d2798e, 0x31e6f1b1, 0x0f7e4d34, 0x126f24ba, 0x2838f5d2, 0x08b1b4de, 0x32d723d4,
-    0x0c683912, 0x372dfe14, 0x2c5603dc, 0x19374b6f, 0x1c8f6eea, 0x1dec2714, 0x032fde3a, 0x23dd5adb,
-    0x27e9d47b, 0x31ad89b9, 0x1380e7b9, 0x10cb33e4, 0x31419355, 0x16b5fbd9, 0x20828aa8, 0x20609f59,
-    0x1cbd5dd4, 0x15c38b0b, 0x186e3c34, 0x23a41d9f, 0x3561ff1f, 0x2eb55122, 0x29e39db4, 0x064b11ed,
-    0x244e1ad9, 0x0d9e1e9e, 0x10758b61, 0x0b2aa678, 0x0bddb021, 0x09c6de0a, 0x12a6cc35, 0x3a1375e1,
-    0x223883b6, 0x2689dc59, 0x31403904, 0x1512ae80, 0x02ecf617, 0x2212117f, 0x0612c4e6, 0x1610afb7,
-    0x2440a9ed, 0x1f50b01e, 0x2d66777e, 0x232428e6, 0x13ccc9b9, 0x2ba06615, 0x36ceeff0, 0x0ed4d844,
-    0x16f8033d, 0x2bf74b8a, 0x0b5abbd0, 0x34a2e136, 0x047920da, 0x08128e8d, 0x11e397d9, 0x267634fa,
-    0x0abd671c, 0x282aca5a, 0x33407726, 0x0765645c, 0x094c51f5, 0x1069ad82, 0x0dfb846e, 0x39f0b209,
-    0x069e3dec, 0x063a2642, 0x1c33e6f0, 0x0e8f3923, 0x0fcb5687, 0x34945f27, 0x0a13cf7e, 0x15468048,
-    0x2a437fc9, 0x36fec763, 0x175b7564, 0x2ae1dfea, 0x04c9de9d, 0x0dfe7163, 0x20a549bc, 0x323b6ca6,
-    0x267aed5d, 0x118bf42f, 0x1aff937c, 0x28a97551, 0x3b1056f3, 0x07e89b27, 0x272efc7b, 0x3857fbc0,
-    0x33e00057, 0x2cdbedfb, 0x19ed6cd2, 0x19e1e400, 0x130f4b27, 0x2b06f98b, 0x24c642c0, 0x1a9d0461,
-    0x09bcf3d4, 0x0e71f00b, 0x0a3b5c9a, 0x26478152, 0x2e48bd34, 0x1277bdc2, 0x09d2ac99, 0x2a67dcd6,
-    0x0d1c7dba, 0x03d35c95, 0x10b758fc, 0x0b9e99d9, 0x012e0eee, 0x17b2e779, 0x25e67d38, 0x118189d8,
-    0x2ee77782, 0x0ae5f7a5, 0x0de79422, 0x03ca1a0a, 0x25267c44, 0x263bbdfd, 0x2ffecfb3, 0x33e8a880,
-    0x17ef747c, 0x25aa3f77, 0x2688a4e2, 0x2e38b189, 0x24783a5c, 0x19b5217d, 0x3092371f, 0x2af7928d,
-    0x10eabafe, 0x363454f7, 0x02a75be2, 0x37b2cb7a, 0x0635d2be, 0x0b491c4d, 0x26910132, 0x0476f8a4,
-    0x3134ddee, 0x282d1059, 0x0e9bb8f3, 0x124a5f77, 0x026bbc44, 0x0825f566, 0x04a160e2, 0x1a70f08d,
-    0x1b792f7c, 0x0d3a400a, 0x28465f00, 0x0e5bcb8c, 0x30fbba64, 0x2ac88bf4, 0x17a009e1, 0x1e5d7ce7,
-    0x175611b1, 0x1efc1406, 0x38bf10d1, 0x1c997106, 0x366a893e, 0x130d00ce, 0x1656ea71, 0x09843659,
-    0x07946c86, 0x09ac9ef9, 0x0ac4e62b, 0x02c37894, 0x21f57b92, 0x082865db, 0x007142cd, 0x17f9f51c,
-    0x25af75ff, 0x0dad5bd6, 0x13e01ec3, 0x331826b5, 0x15254c37, 0x06613edb, 0x0726c86d, 0x0cf21ad2,
-    0x1ea2461c, 0x084d7ff0, 0x384798cc, 0x01716d39, 0x0d74f9ec, 0x335b7755, 0x18b4e524, 0x2a17ad84,
-    0x2fad0ba0, 0x12bb204c, 0x175ebe11, 0x20c7665f, 0x2f416b7b, 0x1212f5bb, 0x343731ee, 0x150012de,
-    0x04968b0e, 0x03b7d186, 0x28550034, 0x23ea8b85, 0x02fdc6ea, 0x389e883f, 0x1718af23, 0x2166ff5f,
-    0x0e59f16d, 0x1d091f7f, 0x0fe3eb3c, 0x0591c591, 0x20e5b68b, 0x0c361ef8, 0x2a520351, 0x117c1463,
-    0x15eee015, 0x333cb63f, 0x297f0279, 0x148ca0ef, 0x23674e09, 0x07aa9f3e, 0x37248956, 0x0b555376,
-    0x2c1eb9d0, 0x20996a1d, 0x2291a731, 0x28f42fd2, 0x0bf0bf40, 0x1448b15e, 0x001dee3e, 0x0b0c10f9,
-    0x38a2b958, 0x10bad7cd, 0x26b09935, 0x253282b1, 0x201dad2c, 0x25ec4859, 0x35848c40, 0x37774266,
-    0x236b759c, 0x2af56483, 0x18d3192f, 0x3428d906, 0x38539863, 0x10ed2b45, 0x32253f6f, 0x3af8fb0c,
-    0x05fdae5b, 0x212eabab, 0x0017d2d7, 0x2d5b932a, 0x2dfadd2d, 0x0521a517, 0x11092b70, 0x036259f0,
-    0x150b9afb, 0x29e78ee5, 0x1238de79, 0x22c4de43, 0x176df2b6, 0x21332099, 0x144bcc32, 0x1cde7c08,
-    0x285ed089, 0x2b8bae10, 0x2e0d6091, 0x2dbb1169, 0x2da2a39e, 0x319bfc6d, 0x1815b6c3, 0x1248feae,
-    0x201d1273, 0x385b3c45, 0x37541c81, 0x23c452ab, 0x0cdbe438, 0x07895474, 0x3613e654, 0x0c34487f,
-    0x26acf5dd, 0x28e8759d, 0x3305dbb1, 0x3209a1e3, 0x31c425ad, 0x1125889d, 0x22d4725e, 0x386edf62,
-    0x27e55d50, 0x34960226, 0x04e6f759, 0x290151cb, 0x0320994e, 0x232823a5, 0x120944e1, 0x27a21d2b,
-    0x092b4754, 0x082d2140, 0x1c2d9d20, 0x01f7f279, 0x27adea49, 0x0380be8f, 0x07e40335, 0x22a49959,
-    0x15c8a12c, 0x1f4d63de, 0x2b4b6f67, 0x233c0d3c, 0x1e4ff34a, 0x17cadf87, 0x35aa7d8f, 0x073207da,
-    0x191fc7b2, 0x2820d373, 0x19d81ddb, 0x004ead31, 0x0b6d337f, 0x05514f60, 0x295e11b9, 0x2946dfc8,
-    0x12d1e103, 0x284f9d4c, 0x2eb85f14, 0x1959a9ce, 0x1de63ae7, 0x17afddc0, 0x111dc025, 0x212ca438,
-    0x23a7d8ec, 0x0ed34295, 0x3b2fea8e, 0x38ada2d3, 0x328cc1ae, 0x372795d1, 0x16e43607, 0x03c63260,
-    0x1d98df3c, 0x27bd4de0, 0x21dc83aa, 0x2755a6a9, 0x0aa088cc, 0x16a8ddee, 0x18939d43, 0x35cee2f5,
-    0x235ca79b, 0x3674468b, 0x19e00401, 0x1a037b6b, 0x2993a8cc, 0x26746971, 0x2efbfa22, 0x0f44ccdf,
-    0x20186cf3, 0x09578b51, 0x2646ab4c, 0x049fd743, 0x0c0a87ee, 0x149a06ad, 0x1e4b546f, 0x2861f9b4,
-    0x1f06b1ea, 0x37eb00a4, 0x016faba6, 0x287f830f, 0x0530b6ab, 0x36d4dc92, 0x05f48ec8, 0x048fac4c,
-    0x0fc726b3, 0x1e1b9986, 0x335f853d, 0x0ae8f783, 0x2c28a94c, 0x2fd58da4, 0x214c4eb0, 0x03e9166e,
-    0x3480fdda, 0x0eff7b97, 0x07b842b8, 0x16acad6a, 0x2c64bf83, 0x00b978f8, 0x3255bcdf, 0x2665e99c,
-    0x0e754618, 0x15f6522f, 0x1a5b09ed, 0x21aaa554, 0x3120a0ae, 0x21e2cc8e, 0x12c78df3, 0x14f7c0eb,
-    0x28a78a97, 0x156c36a2, 0x14758d50, 0x3a54a4f1, 0x05fe198e, 0x32b1cd13, 0x016a7c3a, 0x012f7c57,
-    0x190509b4, 0x04722221, 0x1cc3f2f8, 0x2aefe199, 0x2cbe4c7d, 0x1ac55278, 0x1c1af00c, 0x2bc16b70,
-    0x04cb67ea, 0x0b15eab6, 0x07e83779, 0x1bfa92e9, 0x000f3b08, 0x2767564f, 0x327947be, 0x1205e3d0,
-    0x094fb5ea, 0x0252be5f, 0x15ec312a, 0x17a739b4, 0x061468c6, 0x21a8e2b2, 0x00b5fac6, 0x1cb34367,
-    0x0457b328, 0x32ddcf8a, 0x12589650, 0x30ad5943, 0x39369111, 0x1116aadf, 0x1ad1cc1e, 0x0c77ebb6,
-    0x31b050cd, 0x22a75cc6, 0x087e9185, 0x0f8698e5, 0x35cd72d4, 0x0bc5e702, 0x272a018a, 0x04a9ebbb,
-    0x143addb4, 0x1deae085, 0x249700d9, 0x3643db1f, 0x21d33e2c, 0x221ec269, 0x347ff561, 0x218419e3,
-    0x21e18b73, 0x3b343836, 0x33232979, 0x11d25503, 0x24ced2d0, 0x0cef98f1, 0x03ee9c5f, 0x2b0bb546,
-    0x0211f429, 0x3171c443, 0x0271bff0, 0x0196d7d8, 0x0ae44fc6, 0x16847e82, 0x0a1d7807, 0x26e9a34a,
-    0x2ccd10fa, 0x0bfbc1ff, 0x03e38b79, 0x368d83bd, 0x2b241218, 0x333f3843, 0x3af8a012, 0x308e5732,
-    0x29538b7d, 0x13659ef9, 0x0e9145c1, 0x082e09c8, 0x0428d30c, 0x16403e01, 0x3520a450, 0x24395b13,
-    0x0b5996e8, 0x21575f45, 0x06f02c63, 0x1c5345d0, 0x0aa8f4b5, 0x270fa040, 0x0e099c42, 0x1de09eb3,
-    0x32d462d2, 0x19731a58, 0x36656b69, 0x20295a28, 0x14ec8731, 0x0e67a7f9, 0x1ba6e554, 0x2cd23a47,
-    0x31f6deb1, 0x2210ef21, 0x2e2c7eb4, 0x0451e7ed, 0x215e6a6a, 0x1cb2e637, 0x1838d73b, 0x2f82cd94,
-    0x0a72c5c0, 0x01c4adec, 0x17031650, 0x2a1e61fa, 0x0b5ea3c7, 0x1d01c8e4, 0x06a897d9, 0x0a0d5efc,
-    0x19e682ed, 0x29c69a1e, 0x26a01060, 0x09c2ebc5, 0x10c34165, 0x156026eb, 0x10ad7a16, 0x2f91be1d,
-    0x110dc6d2, 0x3626fd06, 0x38eb683f, 0x323d18a5, 0x273260b4, 0x10d4b822, 0x2329251e, 0x059d89af,
-    0x3747c42e, 0x053f962e, 0x1407f40b, 0x35c9d001, 0x2c8b93dd, 0x0d19fd6e, 0x085004e8, 0x25f4894b,
-    0x01d12f6f, 0x0563f589, 0x2bce365b, 0x2670f3b3, 0x26994e10, 0x1f0c1dd4, 0x01eece3d, 0x30001263,
-    0x11dfef0f, 0x12041bae, 0x133b1b71, 0x0209b8b8, 0x0662dbfc, 0x29416eb2, 0x356219ee, 0x0352044f,
-    0x10a55c05, 0x39265e2e, 0x07272c8f, 0x1a0ba36b, 0x3379a5af, 0x26d6beb5, 0x1d68f1a4, 0x0dfb89e8,
-    0x291e4044, 0x25016138, 0x0eb82ea2, 0x067a55ed, 0x2bf970d0, 0x312cd0a3, 0x2ff7e4f5, 0x18d77901,
-    0x27716c81, 0x05181a46, 0x0cc97473, 0x0ddc1ea1, 0x39baae2a, 0x0e7b640b, 0x23e0bac7, 0x03123adf,
-    0x0ec382d2, 0x08e58afc, 0x0285afd1, 0x248c00f0, 0x1b1831f0, 0x108f7644, 0x11cb654b, 0x2737d5fe,
-    0x2c0aebb6, 0x213fea46, 0x2738a46c, 0x36e781ef, 0x08bd24a0, 0x0d9c1bcf, 0x0e72df5d, 0x237661b8,
-    0x1f8b482e, 0x3aa41ba3, 0x072446a2, 0x07e08dff, 0x32ed9788, 0x146e9f6b, 0x1e9037da, 0x3625cab8,
-    0x1b5973a5, 0x377c858c, 0x2070381f, 0x24c83520, 0x34fecb81, 0x1cc49d8c, 0x14cb4565, 0x261ecd51,
-    0x30c3da33, 0x1de3a54b, 0x009d2b46, 0x07d34fcf, 0x0413a362, 0x34c0626e, 0x25f93456, 0x34b371c0,
-    0x34897cff, 0x0965d321, 0x29e58ba8, 0x111a8466, 0x28708c23, 0x264669a6, 0x07c59619, 0x1b37367c,
-    0x23458195, 0x1bc34dcf, 0x3a4440f2, 0x14b26262, 0x12baf842, 0x3672aaeb, 0x26b76b15, 0x2e361d0a,
-    0x16cabc78, 0x0c69c450, 0x1087abc1, 0x0ee7e63a, 0x060806da, 0x2b9ee2ac, 0x122e9bd3, 0x126affa2,
-    0x268bc6da, 0x38fdf1b7, 0x33d9d077, 0x024350ef, 0x2e926e78, 0x1ae57e19, 0x317c1d2e, 0x3318a9da,
-    0x3014cf85, 0x161c4eac, 0x1b04082c, 0x1b74edab, 0x34546585, 0x25be50f3, 0x08a5e905, 0x1915cd6a,
-    0x139a65f8, 0x13997461, 0x3429f04f, 0x0ae90818, 0x0379a377, 0x363da904, 0x16f75941, 0x1131a54f,
-    0x1a50ccc7, 0x145c8776, 0x1469f6ca, 0x39d4e3ff, 0x0c8ef78e, 0x29a85edd, 0x0d1e6298, 0x0aeb1935,
-    0x149f4b0f, 0x0047f1b8, 0x1231a0ef, 0x306be12d, 0x2ece65e8, 0x0f5bc04f, 0x02ce4f37, 0x306d760b,
-    0x18f05f12, 0x10a9a206, 0x2740744c, 0x0151244d, 0x3b50e28b, 0x1ed86e6b, 0x14d3a5f9, 0x1f058414,
-    0x2d07a405, 0x10f81870, 0x152fcc22, 0x3643371d, 0x3aeffdad, 0x01db54c1, 0x2a394ac1, 0x07e500d6,
-    0x32c4b77c, 0x042fc2ff, 0x05199dae, 0x229be475, 0x10e77932, 0x2e2167e5, 0x175b250f, 0x2f60621b,
-    0x2cc1ce93, 0x0cc88508, 0x2f36dee1, 0x0b6f2a89, 0x33065168, 0x0e9bddca, 0x22d75754, 0x0a974125,
-    0x306331e5, 0x31279924, 0x0024a647, 0x1b6f9b5d, 0x2d80cc8f, 0x32fa9808, 0x39151e98, 0x3822ceb1,
-    0x2a3a80b5, 0x1c2549f3, 0x13f69991, 0x1077c17a, 0x067b79a1, 0x0388671f, 0x378fd370, 0x2ce09688,
-    0x2a0cff31, 0x2ba9de75, 0x0c4a825f, 0x15b10d4a, 0x2c7ccac2, 0x37d09c1d, 0x2ce3d1ef, 0x3718aff0,
-    0x39f3574f, 0x10d13629, 0x124d8d6a, 0x07d4a824, 0x2ac98e34, 0x0f6cbdc8, 0x155cea1c, 0x0b32ab8e,
-    0x20166229, 0x1e5f473d, 0x279b29c4, 0x1b61d7aa, 0x0e8d1fdd, 0x318966bc, 0x2908df34, 0x1dd80668,
-    0x03b9f3e3, 0x375b63e5, 0x2205bc43, 0x39e31039, 0x3a328e14, 0x18d5f033, 0x1fd56b77, 0x2e01e2f7,
-    0x077d77a8, 0x076b7211, 0x17a0fd9a, 0x1d80145d, 0x2d8a8e44, 0x3247ddb4, 0x3018d4ae, 0x267b08e0,
-    0x2557672d, 0x13095e7f, 0x186072f6, 0x067f11dd, 0x0c338d06, 0x25798315, 0x0a5c15ca, 0x2f176117,
-    0x0074ea3b, 0x013b912d, 0x306dea41, 0x0502ccc6, 0x2ad9087f, 0x128fa800, 0x3343bb7e, 0x05bc147d,
-    0x27a469e5, 0x0c035a61, 0x111e2b5a, 0x21e20812, 0x0a79da4e, 0x395312f7, 0x1cf0ca4d, 0x05601a44,
-    0x1787b1bb, 0x0762bd9f, 0x2c11128e, 0x185c41e9, 0x303c6d38, 0x170fdbba, 0x1612afcb, 0x18d23ade,
-    0x312e9a84, 0x32881002, 0x05f353d1, 0x30ac22aa, 0x3a13ed19, 0x03180ed3, 0x290e974f, 0x1847a175,
-    0x13dbaf15, 0x0cd425d2, 0x0a69a21a, 0x390a9a67, 0x2aff6852, 0x205c9f28, 0x342e553c, 0x159c80f6,
-    0x3a63bdcd, 0x05e231be, 0x18f5e103, 0x13a788db, 0x1aad69ee, 0x25c2088e, 0x29dd2b50, 0x0b9c8e4e,
-    0x356f0842, 0x32c25503, 0x0b7483f0, 0x08964df2, 0x2a3014d0, 0x3a8e22db, 0x0d8b093e, 0x2adcd284,
-    0x32f86346, 0x31823d65, 0x0f20a1b6, 0x17e28db8, 0x058591c7, 0x11d1ef32, 0x2b08d497, 0x205d3e8e,
-    0x3aab9321, 0x021d9f33, 0x17ad436b, 0x2c2648b7, 0x019de5e2, 0x31044361, 0x3b405faa, 0x0fb5f288,
-    0x0e1a7923, 0x01fd36c5, 0x27080303, 0x14807d37, 0x328966e3, 0x2077c201, 0x23f1a88f, 0x0ff68f3c,
-    0x1438f936, 0x2545ee4f, 0x2316090b, 0x2dc872bc, 0x0ec695be, 0x0e425a09, 0x1d2f8ce9, 0x232850da,
-    0x382962e2, 0x2308833e, 0x0a01bcd5, 0x2ac9f349, 0x228c46b5, 0x129df0ed, 0x0125fea0, 0x0da68e92,
-    0x3027018c, 0x2ef5b07b, 0x0c60d7f0, 0x145010f1, 0x24a955d7, 0x39ac1636, 0x1a1756ec, 0x0aeb3fca,
-    0x08f13ab0, 0x3b09ceae, 0x20c13ae6, 0x211e4e1b, 0x15e2a09a, 0x352ba4ce, 0x36952c46, 0x26d58d48,
-    0x2cc9e643, 0x2cda45cf, 0x1dcf2424, 0x058e1ee2, 0x06e57bb3, 0x145f6cde, 0x22357c7b, 0x12c80e93,
-    0x1298c1c5, 0x117dc496, 0x2005a910, 0x099d6b84, 0x318c3387, 0x20b47ac5, 0x38ed0ddd, 0x31cbd14a,
-    0x301122a4, 0x2df8cbe1, 0x22ce8b08, 0x12b0eb50, 0x2ff1a40a, 0x117d484e, 0x1f9a0040, 0x05fa3be5,
-    0x13ef8b6f, 0x32ff864d, 0x02e6ec80, 0x30813854, 0x312f5cd2, 0x01e8cf5c, 0x2236bc71, 0x34d65fa8,
-    0x1960d6af, 0x07f3c13a, 0x2e1a488f, 0x2d2e8bf9, 0x1a0beca1, 0x28d14a39, 0x145ef3d4, 0x04e476be,
-    0x02e88c86, 0x15eb8aa8, 0x25a19c15, 0x33377f0a, 0x0008bb22, 0x37ca138a, 0x1b4b995e, 0x23b6a510,
-    0x272be2f6, 0x0ecc622a, 0x25a4361c, 0x1fe160bb, 0x1ff8bb65, 0x12abb430, 0x1e6eb0a3, 0x13776d28,
-    0x06f854b1, 0x2678c42f, 0x273f5e2f, 0x1ff862bd, 0x05a33972, 0x1ac92ced, 0x33966bd8, 0x221b1e19,
-    0x0aa6f83a, 0x1d1e4980, 0x393e8e43, 0x013c6ac8, 0x0c087c0a, 0x2f778cba, 0x1dff3106, 0x06cec6e5,
-    0x3854dc50, 0x22036679, 0x2c36cba1, 0x01971157, 0x256b6ad1, 0x0bf1bb35, 0x36ffdd50, 0x06409e21,
-    0x0926fa0a, 0x23205eb8, 0x31b2bed7, 0x1aadbcee, 0x2bfdea43, 0x33d73fd3, 0x13a585f4, 0x03aaad1c,
-    0x18255b61, 0x23d788b0, 0x22931704, 0x05eb3777, 0x1099fe2f, 0x0866df7b, 0x0d377045, 0x3003cf4b,
-    0x28479fd5, 0x05a513a5, 0x26f6dacb, 0x21f2e803, 0x2d35bbc3, 0x1da06d97, 0x2f7daf03, 0x361ef31f,
-    0x2e4edf59, 0x199affef, 0x1d2c53aa, 0x29a225db, 0x2a7a9f1b, 0x07ef0a29, 0x0aa176c1, 0x0c50f409,
-    0x11179de5, 0x179c14d0, 0x0d285dc9, 0x340c9ecb, 0x1721806e, 0x2d0d7666, 0x0c0243ea, 0x1e6427d6,
-    0x1a95fbf3, 0x25c99a8d, 0x01531ef0, 0x1d745032, 0x1b8bd6e7, 0x3711d7f7, 0x062e11d2, 0x33e70ebd,
-    0x019349ac, 0x0f80187f, 0x0e57577b, 0x191a0d38, 0x1e981db8, 0x2ed37fe4, 0x039a241d, 0x1126906b,
-    0x129330b4, 0x099114cf, 0x04f68f57, 0x1a530801, 0x3035b964, 0x1ca340ef, 0x231bd2ef, 0x3b2484b4,
-    0x253fc1ee, 0x08b9cdf1, 0x2f56a1b4, 0x11487882, 0x049e2604, 0x1bf37720, 0x21558ff5, 0x2b7a504d,
-    0x19bf4281, 0x28f19a06, 0x1b5ec752, 0x0695248a, 0x1fdf7388, 0x08b4c7bc, 0x2ec091f2, 0x1a6a29e3,
-    0x04691b58, 0x1931c314, 0x135b7c44, 0x34387981, 0x0badf8f3, 0x18b841f3, 0x305575cb, 0x24e6aa06,
-    0x0e679906, 0x0da8ff82, 0x2fbdd240, 0x1e4bf66a, 0x27e37cc4, 0x2b976b63, 0x1545d346, 0x2495fa78,
-    0x3a7bd021, 0x314c1e22, 0x1bb5021c, 0x0da9a06a, 0x37cd6b91, 0x21fa86aa, 0x3461db96, 0x192ed088,
-    0x0ff375f8, 0x165cbaad, 0x262bad5b, 0x2617a261, 0x27992621, 0x325d3224, 0x0b999f15, 0x35c0467f,
-    0x03bc02ea, 0x27a574c0, 0x136cde7f, 0x2a8d049f, 0x2f7d64fc, 0x26e25800, 0x0ea523ef, 0x19b90715,
-    0x33bf5220, 0x3632a2b6, 0x1de8da54, 0x15ee6244, 0x01e15b4a, 0x21652a2f, 0x246dcea9, 0x0bdfe5bd,
-    0x25d2c980, 0x2fb8f5d8, 0x21b504ee, 0x2e17cbed, 0x35ce0846, 0x2953f242, 0x0c0312cc, 0x24bfdca8,
-    0x21d5522f, 0x2b6e9a0c, 0x1a4167dd, 0x066d21d5, 0x096a9bc6, 0x1ebc6354, 0x32b4cbb8, 0x19a83d7b,
-    0x2304e0cb, 0x18e6a6d1, 0x0c172d2b, 0x235fb98b, 0x264c687a, 0x3774cbc1, 0x1c9107a3, 0x0fc383ed,
-    0x2d13edd2, 0x32f16b4d, 0x2adae632, 0x0f52b868, 0x0a85ee5c, 0x1101b6bc, 0x0a51b187, 0x2a3e7e07,
-    0x0190c593, 0x049963d9, 0x11cbb5a9, 0x2801eba9, 0x1610fa0e, 0x340cec18, 0x330a1ee4, 0x07be17d7,
-    0x38cacfcb, 0x0d1663e7, 0x0ba73cb7, 0x267b4a36, 0x2fcc53d2, 0x31a62646, 0x0ca5bffa, 0x221ec808,
-    0x20a57c27, 0x026df2cf, 0x19c19a27, 0x033ebea8, 0x10abb0eb, 0x1e003a8e, 0x11e14492, 0x1758679c,
-    0x3344e087, 0x26f67185, 0x2c3ce061, 0x031683b4, 0x14ed303e, 0x1adc8615, 0x245d8764, 0x317a1c4d,
-    0x10f2574a, 0x1272a537, 0x143ea466, 0x1edb9660, 0x2f11b814, 0x30f7cb2b, 0x02293099, 0x1ab8d758,
-    0x0a5dd180, 0x0b65669a, 0x28db62a6, 0x158c50b4, 0x20beb760, 0x0fdd676d, 0x09fcce6f, 0x14a817b6,
-    0x1209ba77, 0x22b1de89, 0x344a4c46, 0x33945aa4, 0x1714bdf5, 0x287fc219, 0x0d38b92a, 0x2a342699,
-    0x24f98cd0, 0x2a31fed1, 0x0167d6af, 0x1e55bdb0, 0x1a5cbc5f, 0x1e59823b, 0x1a7d2692, 0x0726526c,
-    0x2def60dc, 0x172bbe89, 0x22937d25, 0x1c240ba0, 0x31b340be, 0x2bdbc73c, 0x13e1cdc6, 0x289f4b6a,
-    0x370fb428, 0x2df45dcf, 0x1a693b1b, 0x0197fe16, 0x255e4fba, 0x19503896, 0x1ad37e4f, 0x1d2975d8,
-    0x29bb99b7, 0x06c9712a, 0x352e5bf3, 0x3918bc96, 0x219f965b, 0x1340c983, 0x386745c2, 0x34e63606,
-    0x0da2c9a1, 0x2912af3b, 0x0266e3c5, 0x189aad61, 0x34b8f076, 0x0dcd6e6d, 0x0e962a05, 0x2854caf6,
-    0x24631cdb, 0x0e71cd3d, 0x13896d5c, 0x118d15d4, 0x2487437c, 0x34aa4e4e, 0x0d39f275, 0x13fa7123,
-    0x3566c668, 0x2834126b, 0x13cca490, 0x2dc6e3bb, 0x1aea47f6, 0x15e488dd, 0x22fd6a31, 0x2e47d0d7,
-    0x15bf287a, 0x095a1acf, 0x281cc166, 0x00725e64, 0x204ec978, 0x0c7cc229, 0x36587fd1, 0x1ffa813f,
-    0x361a6ec1, 0x1b3ea87c, 0x1837b0ca, 0x24752cc9, 0x0b51688b, 0x20f719a3, 0x33c0eb3c, 0x32ab1292,
-    0x27bcdba0, 0x39914614, 0x06ab8fef, 0x027cf535, 0x02c6de69, 0x13775223, 0x10492be3, 0x26cba246,
-    0x330fc8ad, 0x14b44a57, 0x18f0829b, 0x33bde672, 0x2e8b9865, 0x211d649e, 0x2282cf28, 0x3440dd7a,
-    0x229a962e, 0x0b66b4f1, 0x2b6b1e94, 0x2932e48a, 0x38bf2b7b, 0x093b7775, 0x035d30d4, 0x05452804,
-    0x09b5e6ef, 0x1554e55d, 0x1c59788b, 0x0402a2b3, 0x398fe25d, 0x16b1ba12, 0x206fc365, 0x1dfec286,
-    0x20fcd048, 0x046a2390, 0x0faef433, 0x2791d7f6, 0x32d6d603, 0x14feb97a, 0x00fa437b, 0x3a166013,
-    0x193b5cb6, 0x03f1f5a2, 0x332c8aba, 0x0fcd4c6f, 0x2da378a4, 0x0dabc608, 0x22246a17, 0x2a2f3ec2,
-    0x3238a033, 0x064969dd, 0x1d004401, 0x34f8186a, 0x38bae61b, 0x1729afc6, 0x0a954d25, 0x397379e3,
-    0x1da8aca0, 0x1d7e1d7f, 0x011a4766, 0x089bbf1a, 0x06508f84, 0x2230f082, 0x2088e21d, 0x076458e3,
-    0x32fc2de7, 0x10c005f7, 0x14804bed, 0x1ebdcaed, 0x18274e17, 0x08037e73, 0x30acd185, 0x261665f8,
-    0x1e27725a, 0x1c94264c, 0x312d3460, 0x23897670, 0x2cb7ab8b, 0x2a584341, 0x1533b70c, 0x0f5792b6,
-    0x02e0f0a2, 0x04c65ea6, 0x01ca44cf, 0x16851393, 0x162b5480, 0x34994ceb, 0x27b2d4bb, 0x273a393f,
-    0x0bd4c32b, 0x14b1f771, 0x3af59700, 0x113618d7, 0x278bf87b, 0x0d53029f, 0x35db1810, 0x304f7159,
-    0x345f81b6, 0x0e8382bc, 0x11391bed, 0x345b6531, 0x17d49292, 0x0e6b23ea, 0x1d4ec99a, 0x02c3f12c,
-    0x07c4c952, 0x1ba7fd14, 0x279ceb45, 0x00f6351a, 0x35ec71cf, 0x261638f8, 0x110c16d5, 0x188048a6,
-    0x33fe33fe, 0x0f08b560, 0x2c569318, 0x0f9cc9bb, 0x0b172bfb, 0x0ef2fc64, 0x2064c55d, 0x1ef4bf3f,
-    0x2bf65be9, 0x3867e67e, 0x0ba0ad0b, 0x00a5de13, 0x0505be41, 0x084c23b9, 0x313be9d8, 0x395a3676,
-    0x32b80ea1, 0x1e010015, 0x1fac85fe, 0x33a8c951, 0x122a4b1f, 0x0b567ba9, 0x3825e312, 0x26e4bca4,
-    0x29b0a374, 0x21678fdc, 0x368e1c28, 0x267cf48a, 0x299ec2e9, 0x0c7621fd, 0x0193582f, 0x289b4938,
-    0x1b24e130, 0x2cc1d407, 0x0f00fb5e, 0x0ec4dd7e, 0x0566e91e, 0x2eb988cd, 0x388094a1, 0x27fed4db,
-    0x3a3a204d, 0x1b0df714, 0x1995d822, 0x2c2d9d66, 0x2d30ef11, 0x247dce19, 0x2fb10567, 0x3472eb31,
-    0x3b94abfa, 0x237b795b, 0x30ef7f44, 0x39545aec, 0x332373db, 0x31c6d939, 0x08c097a7, 0x117b25e7,
-    0x3467507f, 0x11e0502b, 0x096c9dc2, 0x10f8f0de, 0x1c8b068b, 0x0f93594d, 0x0b17bdf1, 0x181c61af,
-    0x1bb3e85b, 0x05b0f37b, 0x04399364, 0x3b7635e2, 0x326aaa2b, 0x127cab69, 0x05c2afd3, 0x01c7ddb5,
-    0x183b3089, 0x19675622, 0x237cf46d, 0x08be00ba, 0x2b19aa35, 0x194f8a7e, 0x2a5816fe, 0x20c6b170,
-    0x25d68194, 0x2e760643, 0x1148522b, 0x07824c5b, 0x154f2e31, 0x2885693d, 0x05bb2554, 0x22d3bae0,
-    0x2183c28e, 0x1eb9a36f, 0x05ffef64, 0x1939d5fd, 0x293b20fe, 0x2c372eaa, 0x28bb52e0, 0x13b2c03d,
-    0x24185c81, 0x300b4f0b, 0x0c3fbdbd, 0x0c02cc7f, 0x07fa1793, 0x2cf2ce23, 0x2abc48ec, 0x0d25923b,
-    0x278b7117, 0x379eb818, 0x201a6b4b, 0x175b206d, 0x25510496, 0x20874ff9, 0x32faeba0, 0x192a467e,
-    0x3870ec22, 0x30c29c45, 0x2155e2aa, 0x0b87c838, 0x2f50e2fa, 0x193c467c, 0x22fa15eb, 0x3091d7ab,
-    0x33f17cd4, 0x05301411, 0x193ffe21, 0x3a4d8116, 0x2bed6f3a, 0x1169ce5e, 0x212a4c60, 0x22d3a454,
-    0x2423fe7a, 0x00b69619, 0x3050d9e9, 0x0a483f9d, 0x329a4942, 0x0f3bcd9c, 0x272bac48, 0x092e316a,
-    0x13759dee, 0x05b38707, 0x1a97d2db, 0x07cd8616, 0x029e5e15, 0x29597855, 0x1009cca7, 0x12dbf5b2,
-    0x1766dd8d, 0x02c6d511, 0x1c6d481a, 0x0ad3ae69, 0x2de614af, 0x3412543e, 0x26830d3d, 0x30742e88,
-    0x374df7db, 0x2fc89fb7, 0x01118009, 0x144c9baf, 0x2077f790, 0x39f7f363, 0x157d2e5a, 0x0113f233,
-    0x104d91ea, 0x265e5e6b, 0x165ffbb6, 0x1e9da5de, 0x2fc54e14, 0x069b9c00, 0x1e44a027, 0x1d757f3e,
-    0x1851082c, 0x1fd6ff85, 0x2dc631d5, 0x2f33d986, 0x29aad533, 0x18b3261b, 0x0090d74b, 0x1f0788e2,
-];
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: usize = 10_000;
+
+    /// First outputs of `pcg32_srandom_r(&rng, 42, 54)` from the reference
+    /// `pcg32-demo`.
+    #[test]
+    fn matches_reference_output() {
+        let mut rng = Rng::with_stream(42, 54);
+        let expected = [
+            0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+        ];
+        for value in expected {
+            assert_eq!(rng.u32(), value);
+        }
+    }
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..SAMPLES {
+            assert_eq!(a.u32(), b.u32());
+        }
+        assert_ne!(Rng::new(7).u64(), Rng::new(8).u64());
+        assert_ne!(Rng::new(7).fork(), Rng::new(7));
+    }
+
+    #[test]
+    fn raw_state_resumes_the_sequence() {
+        let mut rng = Rng::new(7);
+        rng.u32();
+        let (state, inc) = rng.to_raw();
+        let mut restored = Rng::from_raw(state, inc);
+        for _ in 0..100 {
+            assert_eq!(rng.u32(), restored.u32());
+        }
+    }
+
+    #[test]
+    fn f32_is_in_unit_interval() {
+        let mut rng = Rng::new(1);
+        for _ in 0..SAMPLES {
+            let value = rng.f32();
+            assert!((0.0..1.0).contains(&value), "{value}");
+        }
+    }
+
+    #[test]
+    fn ranges_stay_in_bounds() {
+        let mut rng = Rng::new(2);
+        let mut seen = [false; 5];
+        for _ in 0..SAMPLES {
+            let value = rng.range_f32(-3.0..2.0);
+            assert!((-3.0..2.0).contains(&value), "{value}");
+            let value = rng.range_u32(10..15);
+            assert!((10..15).contains(&value), "{value}");
+            seen[value as usize - 10] = true;
+            assert_eq!(rng.range_usize(4..5), 4);
+        }
+        assert!(seen.iter().all(|seen| *seen));
+    }
+
+    #[test]
+    fn disk_and_rect_stay_in_bounds() {
+        let mut rng = Rng::new(3);
+        let half = Vec2::new(4.0, 1.0);
+        for _ in 0..SAMPLES {
+            let p = rng.in_disk(5.0);
+            assert!(p.length() <= 5.0 + 1e-4, "{p}");
+            let p = rng.in_rect(half);
+            assert!(p.cmpge(-half).all() && p.cmplt(half).all(), "{p}");
+        }
+    }
+
+    #[test]
+    fn weighted_skips_empty_weights() {
+        let mut rng = Rng::new(4);
+        assert_eq!(rng.weighted(&[]), None);
+        assert_eq!(rng.weighted(&[0.0, 0.0]), None);
+        assert_eq!(rng.weighted(&[-1.0, 0.0]), None);
+        let mut counts = [0; 4];
+        for _ in 0..SAMPLES {
+            counts[rng.weighted(&[0.0, 1.0, -2.0, 3.0]).unwrap()] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        assert_eq!(counts[2], 0);
+        // a quarter and three quarters, give or take
+        assert!((2000..3000).contains(&counts[1]), "{counts:?}");
+        assert!((7000..8000).contains(&counts[3]), "{counts:?}");
+    }
+}
@@ -0,0 +1,70 @@
+//! Reading rendered frames back from the GPU and saving them as PNGs.
+
+use glow::HasContext;
+use image::RgbaImage;
+
+/// Window resolution multiple used for high resolution stills.
+pub const HIRES_SCALE: usize = 4;
+
+/// Reads `width` by `height` pixels from the bound framebuffer, the first row
+/// of the image is the top of the frame.
+pub fn read_pixels(gl: &glow::Context, width: usize, height: usize) -> RgbaImage {
+    let mut pixels = vec![0; width * height * 4];
+    unsafe {
+        gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+        gl.read_pixels(
+            0,
+            0,
+            width as i32,
+            height as i32,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            glow::PixelPackData::Slice(Some(&mut pixels)),
+        );
+    }
+    let mut image = RgbaImage::from_raw(width as u32, height as u32, pixels).unwrap();
+    // gl rows start at the bottom
+    image::imageops::flip_vertical_in_place(&mut image);
+    image
+}
+
+/// `<prefix>-YYYYMMDD-HHMMSS-mmm` in UTC, so files sort by when they were
+/// taken.
+pub fn timestamped(prefix: &str) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = now.as_secs();
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let time = secs % 86_400;
+    format!(
+        "{prefix}-{year:04}{month:02}{day:02}-{:02}{:02}{:02}-{:03}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60,
+        now.subsec_millis()
+    )
+}
+
+/// Saves `image` to a new timestamped PNG in the working directory and
+/// returns its path.
+pub fn save(image: &RgbaImage) -> Result<String, image::ImageError> {
+    let path = format!("{}.png", timestamped("screenshot"));
+    image.save(&path)?;
+    Ok(path)
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian date, from Howard
+/// Hinnant's `civil_from_days`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
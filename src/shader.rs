@@ -17,20 +17,35 @@ pub fn uniform<F: FnOnce(Option<&glow::UniformLocation>)>(
     }
 }
 
+/// Compiles a vertex and fragment shader pair. Any trailing paths are shared
+/// GLSL sources pasted after the version header of both stages.
 #[macro_export]
 macro_rules! compile_shader {
-    ($gl:ident, $vert:literal, $frag:literal) => {
+    ($gl:ident, $vert:literal, $frag:literal $(, $include:literal)* $(,)?) => {
         $crate::shader::compile_shader(
             $gl,
             #[cfg(not(target_arch = "wasm32"))]
-            concat!("#version 330 core\n", include_str!($vert),),
+            concat!(
+                "#version 330 core\n",
+                $(include_str!($include), "\n",)*
+                include_str!($vert),
+            ),
             #[cfg(target_arch = "wasm32")]
-            concat!("#version 300 es\n", include_str!($vert),),
+            concat!(
+                "#version 300 es\n",
+                $(include_str!($include), "\n",)*
+                include_str!($vert),
+            ),
             #[cfg(not(target_arch = "wasm32"))]
-            concat!("#version 330 core\n", include_str!($frag),),
+            concat!(
+                "#version 330 core\n",
+                $(include_str!($include), "\n",)*
+                include_str!($frag),
+            ),
             #[cfg(target_arch = "wasm32")]
             concat!(
                 "#version 300 es\nprecision mediump float;\n",
+                $(include_str!($include), "\n",)*
                 include_str!($frag),
             ),
         )
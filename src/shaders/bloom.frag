@@ -0,0 +1,12 @@
+uniform sampler2D image;
+// largest bloom level, half the screen size
+uniform sampler2D bloom;
+uniform float intensity;
+
+in vec2 uv;
+out vec4 c;
+
+void main() {
+	vec3 col = texture(image, uv).rgb + texture(bloom, uv).rgb * intensity;
+	c = vec4(col, 1.0);
+}
@@ -0,0 +1,49 @@
+uniform sampler2D image;
+// size of one texel of `image`
+uniform vec2 texel;
+// brightness where the glow starts, 0 keeps everything
+uniform float threshold;
+
+in vec2 uv;
+out vec4 c;
+
+// 13 tap downsample from Jimenez's "Next Generation Post Processing in Call
+// of Duty: Advanced Warfare", avoids the shimmering of a plain box filter
+vec3 downsample() {
+	vec3 a = texture(image, uv + texel * vec2(-2.0, 2.0)).rgb;
+	vec3 b = texture(image, uv + texel * vec2(0.0, 2.0)).rgb;
+	vec3 n = texture(image, uv + texel * vec2(2.0, 2.0)).rgb;
+	vec3 d = texture(image, uv + texel * vec2(-2.0, 0.0)).rgb;
+	vec3 e = texture(image, uv).rgb;
+	vec3 f = texture(image, uv + texel * vec2(2.0, 0.0)).rgb;
+	vec3 g = texture(image, uv + texel * vec2(-2.0, -2.0)).rgb;
+	vec3 h = texture(image, uv + texel * vec2(0.0, -2.0)).rgb;
+	vec3 i = texture(image, uv + texel * vec2(2.0, -2.0)).rgb;
+	vec3 j = texture(image, uv + texel * vec2(-1.0, 1.0)).rgb;
+	vec3 k = texture(image, uv + texel * vec2(1.0, 1.0)).rgb;
+	vec3 l = texture(image, uv + texel * vec2(-1.0, -1.0)).rgb;
+	vec3 m = texture(image, uv + texel * vec2(1.0, -1.0)).rgb;
+
+	return e * 0.125
+		+ (a + n + g + i) * 0.03125
+		+ (b + d + f + h) * 0.0625
+		+ (j + k + l + m) * 0.125;
+}
+
+// quadratic knee so pixels fade in below the threshold instead of popping
+vec3 prefilter(vec3 col) {
+	float knee = threshold * 0.5;
+	float brightness = max(col.r, max(col.g, col.b));
+	float soft = clamp(brightness - threshold + knee, 0.0, 2.0 * knee);
+	soft = soft * soft / (4.0 * knee + 1e-5);
+	float weight = max(soft, brightness - threshold) / max(brightness, 1e-5);
+	return col * weight;
+}
+
+void main() {
+	vec3 col = downsample();
+	if (threshold > 0.0) {
+		col = prefilter(col);
+	}
+	c = vec4(col, 1.0);
+}
@@ -0,0 +1,23 @@
+uniform sampler2D image;
+// size of one texel of `image`, the smaller level
+uniform vec2 texel;
+// spread of the tent filter, in texels
+uniform float radius;
+
+in vec2 uv;
+out vec4 c;
+
+// 3x3 tent filter, additively blended onto the larger level
+void main() {
+	vec2 d = texel * radius;
+	vec3 sum = texture(image, uv).rgb * 4.0;
+	sum += (texture(image, uv + vec2(-d.x, 0.0)).rgb
+		+ texture(image, uv + vec2(d.x, 0.0)).rgb
+		+ texture(image, uv + vec2(0.0, -d.y)).rgb
+		+ texture(image, uv + vec2(0.0, d.y)).rgb) * 2.0;
+	sum += texture(image, uv + vec2(-d.x, -d.y)).rgb
+		+ texture(image, uv + vec2(d.x, -d.y)).rgb
+		+ texture(image, uv + vec2(-d.x, d.y)).rgb
+		+ texture(image, uv + vec2(d.x, d.y)).rgb;
+	c = vec4(sum / 16.0, 1.0);
+}
@@ -0,0 +1,17 @@
+uniform sampler2D image;
+// uv step between taps, along one axis
+uniform vec2 direction;
+
+in vec2 uv;
+out vec4 c;
+
+// one axis of a 9 tap gaussian
+void main() {
+	float weights[5] = float[](0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+	vec4 sum = texture(image, uv) * weights[0];
+	for (int i = 1; i < 5; i++) {
+		sum += texture(image, uv + direction * float(i)) * weights[i];
+		sum += texture(image, uv - direction * float(i)) * weights[i];
+	}
+	c = sum;
+}
@@ -0,0 +1,39 @@
+uniform mat4 inv_view_proj;
+uniform float time;
+uniform vec3 sun_direction;
+uniform vec3 sun_color;
+// world units between the surface and the pebbles
+uniform float floor_depth;
+
+in vec2 uv;
+out vec4 c;
+
+// horizontal shift of a refracted ray per unit of surface slope, about
+// 1 - 1 / 1.33 for air into water
+const float REFRACTION = 0.25;
+const float STRENGTH = 0.15;
+
+void main() {
+	vec3 sun = normalize(sun_direction);
+	vec2 world = (inv_view_proj * vec4(uv * 2.0 - 1.0, 0.0, 1.0)).xy;
+	// the light reaching this point of the floor entered the water upstream
+	// of the sun
+	vec2 entry = world + sun.xy / max(sun.z, 0.1) * floor_depth;
+
+	// rays bend along the slope, so the floor brightens where the surface
+	// curvature focuses them and darkens where it spreads them out
+	const float eps = 4.0;
+	float h = surface_height(entry, time);
+	float laplacian = (
+			surface_height(entry + vec2(eps, 0.0), time)
+			+ surface_height(entry - vec2(eps, 0.0), time)
+			+ surface_height(entry + vec2(0.0, eps), time)
+			+ surface_height(entry - vec2(0.0, eps), time)
+			- 4.0 * h
+		) / (eps * eps);
+	float area = 1.0 + laplacian * floor_depth * REFRACTION;
+	float focus = max(1.0 / max(area, 0.2) - 1.0, 0.0);
+
+	// a low sun spreads the same light over more of the floor
+	c = vec4(focus * STRENGTH * sun.z * sun_color, 1.0);
+}
@@ -0,0 +1,16 @@
+uniform sampler2D image;
+uniform float brightness;
+uniform float contrast;
+uniform float saturation;
+uniform vec3 tint;
+
+in vec2 uv;
+out vec4 c;
+
+void main() {
+	vec3 col = texture(image, uv).rgb + brightness;
+	col = (col - 0.5) * contrast + 0.5;
+	float luma = dot(col, vec3(0.2126, 0.7152, 0.0722));
+	col = mix(vec3(luma), col, saturation) * tint;
+	c = vec4(col, 1.0);
+}
@@ -0,0 +1,8 @@
+uniform sampler2D image;
+
+in vec2 uv;
+out vec4 c;
+
+void main() {
+	c = vec4(texture(image, uv).rgb, 1.0);
+}
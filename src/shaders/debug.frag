@@ -0,0 +1,6 @@
+in vec4 color;
+out vec4 c;
+
+void main() {
+	c = color;
+}
@@ -0,0 +1,11 @@
+layout (location = 0) in vec2 position;
+layout (location = 1) in vec4 acolor;
+
+uniform mat4 proj_matrix;
+
+out vec4 color;
+
+void main() {
+	gl_Position = proj_matrix * vec4(position, 0.0, 1.0);
+	color = acolor;
+}
@@ -0,0 +1,14 @@
+// multisampled fish depth, resolved to the deepest sample of each pixel
+uniform sampler2DMS image;
+uniform int samples;
+
+out vec4 c;
+
+void main() {
+	ivec2 texel = ivec2(gl_FragCoord.xy);
+	float depth = 0.0;
+	for (int i = 0; i < samples; i++) {
+		depth = max(depth, texelFetch(image, texel, i).r);
+	}
+	c = vec4(depth, 0.0, 0.0, 1.0);
+}
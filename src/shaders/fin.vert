@@ -0,0 +1,26 @@
+layout (location = 0) in vec3 position;
+layout (location = 1) in mat4 model_matrix;
+
+uniform mat4 proj_matrix;
+uniform float depth_range;
+uniform float time;
+// world units to grow the fin by, 0 for the fill
+uniform float outline;
+
+out float depth;
+
+void main() {
+	float tau = 3.14 * 2.0;
+	vec3 translation = model_matrix[3].xyz;
+	float ripple_factor = sin(time * tau + translation.x * 0.001);
+	vec3 rippled_position = vec3(0.0, ripple_factor, 0.0) + position;
+	vec4 world = model_matrix * vec4(rippled_position, 1.0);
+	// push outwards from the center, close enough to the normal for the
+	// shallow curvature of an ellipse
+	vec2 outwards = world.xy - translation.xy;
+	if (dot(outwards, outwards) > 0.0) {
+		world.xy += normalize(outwards) * outline;
+	}
+	gl_Position = proj_matrix * world;
+	depth = clamp(-translation.z / depth_range, 0.0, 1.0);
+}
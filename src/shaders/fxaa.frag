@@ -0,0 +1,44 @@
+uniform sampler2D image;
+// size of one pixel in uv
+uniform vec2 texel;
+// longest edge search, in pixels
+uniform float span_max;
+uniform float reduce_mul;
+
+in vec2 uv;
+out vec4 c;
+
+const float REDUCE_MIN = 1.0 / 128.0;
+const vec3 LUMA = vec3(0.299, 0.587, 0.114);
+
+// the compact variant of Lottes' FXAA: estimate the edge direction from the
+// luma of the corners, then blend along it
+void main() {
+	float nw = dot(texture(image, uv + vec2(-1.0, 1.0) * texel).rgb, LUMA);
+	float ne = dot(texture(image, uv + vec2(1.0, 1.0) * texel).rgb, LUMA);
+	float sw = dot(texture(image, uv + vec2(-1.0, -1.0) * texel).rgb, LUMA);
+	float se = dot(texture(image, uv + vec2(1.0, -1.0) * texel).rgb, LUMA);
+	vec3 center = texture(image, uv).rgb;
+	float m = dot(center, LUMA);
+
+	float luma_min = min(m, min(min(nw, ne), min(sw, se)));
+	float luma_max = max(m, max(max(nw, ne), max(sw, se)));
+
+	vec2 dir = vec2(-((nw + ne) - (sw + se)), (nw + sw) - (ne + se));
+	float reduce = max((nw + ne + sw + se) * 0.25 * reduce_mul, REDUCE_MIN);
+	float scale = 1.0 / (min(abs(dir.x), abs(dir.y)) + reduce);
+	dir = clamp(dir * scale, vec2(-span_max), vec2(span_max)) * texel;
+
+	vec3 a = 0.5 * (texture(image, uv + dir * (1.0 / 3.0 - 0.5)).rgb
+			+ texture(image, uv + dir * (2.0 / 3.0 - 0.5)).rgb);
+	vec3 b = a * 0.5 + 0.25 * (texture(image, uv - dir * 0.5).rgb
+			+ texture(image, uv + dir * 0.5).rgb);
+
+	// the wide blend overshot the local range, it crossed another edge
+	float luma_b = dot(b, LUMA);
+	if (luma_b < luma_min || luma_b > luma_max) {
+		c = vec4(a, 1.0);
+	} else {
+		c = vec4(b, 1.0);
+	}
+}
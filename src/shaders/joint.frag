@@ -1,6 +1,12 @@
 uniform vec4 color;
-out vec4 c;
+
+in float depth;
+
+layout (location = 0) out vec4 c;
+// read by the water pass to attenuate deeper fish
+layout (location = 1) out vec4 fish_depth;
 
 void main() {
     c = color;
+    fish_depth = vec4(depth, 0.0, 0.0, 1.0);
 } 
@@ -0,0 +1,114 @@
+// Gradient noise shared by the shaders. `src/noise.rs` mirrors every function
+// here, keep them in sync.
+
+vec2 random2(vec2 st) {
+    st = vec2( dot(st,vec2(127.1,311.7)),
+              dot(st,vec2(269.5,183.3)) );
+    return -1.0 + 2.0*fract(sin(st)*43758.5453123);
+}
+
+vec3 random3(vec3 st) {
+    st = vec3( dot(st,vec3(127.1,311.7,74.7)),
+              dot(st,vec3(269.5,183.3,246.1)),
+              dot(st,vec3(113.5,271.9,124.6)) );
+    return -1.0 + 2.0*fract(sin(st)*43758.5453123);
+}
+
+// https://thebookofshaders.com/edit.php#11/2d-gnoise.frag
+//
+// Reference values, pinned by the tests in `src/noise.rs`:
+// perlin(vec2(0.5, 0.5))   = -0.27783
+// perlin(vec2(1.25, 3.75)) =  0.11248
+// perlin(vec2(-2.3, 0.7))  =  0.28681
+// perlin(vec2(10.1, 4.2))  =  0.01592
+float perlin(vec2 st) {
+    vec2 i = floor(st);
+    vec2 f = fract(st);
+    vec2 u = f*f*(3.0-2.0*f);
+    return mix( mix( dot( random2(i + vec2(0.0,0.0) ), f - vec2(0.0,0.0) ),
+                     dot( random2(i + vec2(1.0,0.0) ), f - vec2(1.0,0.0) ), u.x),
+                mix( dot( random2(i + vec2(0.0,1.0) ), f - vec2(0.0,1.0) ),
+                     dot( random2(i + vec2(1.0,1.0) ), f - vec2(1.0,1.0) ), u.x), u.y);
+}
+
+float perlin3(vec3 st) {
+    vec3 i = floor(st);
+    vec3 f = fract(st);
+    vec3 u = f*f*(3.0-2.0*f);
+    return mix( mix( mix( dot( random3(i + vec3(0.0,0.0,0.0) ), f - vec3(0.0,0.0,0.0) ),
+                          dot( random3(i + vec3(1.0,0.0,0.0) ), f - vec3(1.0,0.0,0.0) ), u.x),
+                     mix( dot( random3(i + vec3(0.0,1.0,0.0) ), f - vec3(0.0,1.0,0.0) ),
+                          dot( random3(i + vec3(1.0,1.0,0.0) ), f - vec3(1.0,1.0,0.0) ), u.x), u.y),
+                mix( mix( dot( random3(i + vec3(0.0,0.0,1.0) ), f - vec3(0.0,0.0,1.0) ),
+                          dot( random3(i + vec3(1.0,0.0,1.0) ), f - vec3(1.0,0.0,1.0) ), u.x),
+                     mix( dot( random3(i + vec3(0.0,1.0,1.0) ), f - vec3(0.0,1.0,1.0) ),
+                          dot( random3(i + vec3(1.0,1.0,1.0) ), f - vec3(1.0,1.0,1.0) ), u.x), u.y), u.z);
+}
+
+// https://weber.itn.liu.se/~stegu/simplexnoise/simplexnoise.pdf
+float simplex(vec2 st) {
+    const float F2 = 0.366025403784;
+    const float G2 = 0.211324865405;
+    vec2 i = floor(st + dot(st, vec2(F2)));
+    vec2 x0 = st - i + dot(i, vec2(G2));
+    vec2 i1 = x0.x > x0.y ? vec2(1.0, 0.0) : vec2(0.0, 1.0);
+    vec2 x1 = x0 - i1 + G2;
+    vec2 x2 = x0 - 1.0 + 2.0 * G2;
+    vec3 t = max(0.5 - vec3(dot(x0,x0), dot(x1,x1), dot(x2,x2)), 0.0);
+    t = t*t*t*t;
+    vec3 n = vec3( dot( random2(i), x0 ),
+                   dot( random2(i + i1), x1 ),
+                   dot( random2(i + 1.0), x2 ) );
+    return 70.0 * dot(t, n);
+}
+
+float simplex3(vec3 st) {
+    const float F3 = 1.0 / 3.0;
+    const float G3 = 1.0 / 6.0;
+    vec3 i = floor(st + dot(st, vec3(F3)));
+    vec3 x0 = st - i + dot(i, vec3(G3));
+    vec3 g = step(x0.yzx, x0.xyz);
+    vec3 l = 1.0 - g;
+    vec3 i1 = min(g.xyz, l.zxy);
+    vec3 i2 = max(g.xyz, l.zxy);
+    vec3 x1 = x0 - i1 + G3;
+    vec3 x2 = x0 - i2 + 2.0 * G3;
+    vec3 x3 = x0 - 1.0 + 3.0 * G3;
+    vec4 t = max(0.6 - vec4(dot(x0,x0), dot(x1,x1), dot(x2,x2), dot(x3,x3)), 0.0);
+    t = t*t*t*t;
+    vec4 n = vec4( dot( random3(i), x0 ),
+                   dot( random3(i + i1), x1 ),
+                   dot( random3(i + i2), x2 ),
+                   dot( random3(i + 1.0), x3 ) );
+    return 32.0 * dot(t, n);
+}
+
+float fbm(vec2 st, int octaves) {
+    float value = 0.0;
+    float amplitude = 0.5;
+    for (int i = 0; i < octaves; i++) {
+        value += amplitude * perlin(st);
+        st *= 2.0;
+        amplitude *= 0.5;
+    }
+    return value;
+}
+
+float fbm3(vec3 st, int octaves) {
+    float value = 0.0;
+    float amplitude = 0.5;
+    for (int i = 0; i < octaves; i++) {
+        value += amplitude * perlin3(st);
+        st *= 2.0;
+        amplitude *= 0.5;
+    }
+    return value;
+}
+
+// the water surface height field, `st` is in uv space
+float multisampled_perlin(vec2 st) {
+    float height = perlin(st * 10.0) * 0.5;
+    height += perlin(st * 20.0) * 0.3;
+    height += perlin(st * 30.0) * 0.2;
+    return height;
+}
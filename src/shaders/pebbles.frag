@@ -1,4 +1,7 @@
 uniform sampler2D pebbles;
+uniform mat4 inv_view_proj;
+// world units covered by one repeat of the texture
+uniform vec2 floor_size;
 
 in vec2 uv;
 out vec4 c;
@@ -23,9 +26,13 @@ void main() {
 		1.0 / 16, 2.0 / 16, 1.0 / 16  
 	);
 
+    // the floor is fixed to the world, not the screen
+    vec2 world = (inv_view_proj * vec4(uv * 2.0 - 1.0, 0.0, 1.0)).xy;
+    vec2 st = world / floor_size + 0.5;
+
     vec3 sampleTex[9];
     for(int i = 0; i < 9; i++) {
-        sampleTex[i] = vec3(texture(pebbles, uv.st + offsets[i]));
+        sampleTex[i] = vec3(texture(pebbles, st + offsets[i]));
     }
     vec3 col = vec3(0.0);
     for(int i = 0; i < 9; i++)
@@ -0,0 +1,10 @@
+uniform sampler2D mask;
+uniform float strength;
+
+in vec2 uv;
+out vec4 c;
+
+void main() {
+	float shadow = texture(mask, uv).r * strength;
+	c = vec4(0.0, 0.02, 0.0, shadow);
+}
@@ -1,8 +1,9 @@
 uniform sampler2D texture1;
 
 in vec2 uv;
+in vec4 color;
 out vec4 c;
 
 void main() {
-    c = texture(texture1, uv);
+    c = texture(texture1, uv) * color;
 } 
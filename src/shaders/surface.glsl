@@ -0,0 +1,24 @@
+// The animated water surface, shared by the water and the caustics so the
+// light on the floor follows the waves above it.
+
+// noise space units per world unit
+const float SURFACE_SCALE = 1.0 / 720.0;
+// world units of height per unit of noise
+const float SURFACE_AMPLITUDE = 14.4;
+
+float surface_height(vec2 world, float time) {
+	vec2 st = world * SURFACE_SCALE;
+	float s1 = multisampled_perlin(st + vec2(-1, 1) * time * 0.1) * 0.5;
+	float s2 = multisampled_perlin(st + vec2(-1, -1) * time * 0.15) * 0.5;
+	return (s1 + s2) * SURFACE_AMPLITUDE;
+}
+
+// height per world unit along x and y
+vec2 surface_slope(vec2 world, float time) {
+	const float eps = 1.5;
+	float h = surface_height(world, time);
+	return vec2(
+			surface_height(world + vec2(eps, 0.0), time) - h,
+			surface_height(world + vec2(0.0, eps), time) - h
+		) / eps;
+}
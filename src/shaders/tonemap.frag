@@ -0,0 +1,56 @@
+uniform sampler2D image;
+// 0 aces, 1 reinhard, 2 agx, in the order of `Tonemapper`
+uniform int tonemapper;
+// linear multiplier applied before the curve
+uniform float exposure;
+
+in vec2 uv;
+out vec4 c;
+
+// https://knarkowicz.wordpress.com/2016/01/06/aces-filmic-tone-mapping-curve/
+vec3 aces(vec3 x) {
+	return clamp((x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14), 0.0, 1.0);
+}
+
+vec3 reinhard(vec3 x) {
+	return x / (1.0 + x);
+}
+
+// https://iolite-engine.com/blog_posts/minimal_agx_implementation
+vec3 agx_contrast(vec3 x) {
+	vec3 x2 = x * x;
+	vec3 x4 = x2 * x2;
+	return 15.5 * x4 * x2 - 40.14 * x4 * x + 31.96 * x4
+		- 6.868 * x2 * x + 0.4298 * x2 + 0.1191 * x - 0.00232;
+}
+
+vec3 agx(vec3 x) {
+	const mat3 inset = mat3(
+			0.842479062253094, 0.0423282422610123, 0.0423756549057051,
+			0.0784335999999992, 0.878468636469772, 0.0784336,
+			0.0792237451477643, 0.0791661274605434, 0.879142973793104
+		);
+	const mat3 outset = mat3(
+			1.19687900512017, -0.0528968517574562, -0.0529716355144438,
+			-0.0980208811401368, 1.15190312990417, -0.0980434501171241,
+			-0.0990297440797205, -0.0989611768448433, 1.15107367264116
+		);
+	const float min_ev = -12.47393;
+	const float max_ev = 4.026069;
+
+	x = inset * max(x, vec3(1e-10));
+	x = (clamp(log2(x), min_ev, max_ev) - min_ev) / (max_ev - min_ev);
+	return clamp(outset * agx_contrast(x), 0.0, 1.0);
+}
+
+void main() {
+	vec3 col = texture(image, uv).rgb * exposure;
+	if (tonemapper == 0) {
+		col = aces(col);
+	} else if (tonemapper == 1) {
+		col = reinhard(col);
+	} else {
+		col = agx(col);
+	}
+	c = vec4(col, 1.0);
+}
@@ -0,0 +1,15 @@
+uniform sampler2D image;
+uniform float strength;
+// distance from the center, in uv, where darkening starts
+uniform float radius;
+uniform float softness;
+
+in vec2 uv;
+out vec4 c;
+
+void main() {
+	vec3 col = texture(image, uv).rgb;
+	float d = length(uv - 0.5);
+	float shade = smoothstep(radius, radius + softness, d);
+	c = vec4(col * (1.0 - strength * shade), 1.0);
+}
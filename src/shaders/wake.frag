@@ -0,0 +1,9 @@
+in float intensity;
+in float across;
+out vec4 c;
+
+void main() {
+	// a smooth ridge, highest along the middle of the arm
+	float ridge = 1.0 - across * across;
+	c = vec4(intensity * ridge * 0.5, 0.0, 0.0, 1.0);
+}
@@ -0,0 +1,14 @@
+layout (location = 0) in vec2 position;
+layout (location = 1) in float aintensity;
+layout (location = 2) in float aacross;
+
+uniform mat4 proj_matrix;
+
+out float intensity;
+out float across;
+
+void main() {
+	gl_Position = proj_matrix * vec4(position, 0.0, 1.0);
+	intensity = aintensity;
+	across = aacross;
+}
@@ -10,74 +10,79 @@
 
 uniform sampler2D framebuffer;
 uniform sampler2D dudv;
+uniform sampler2D ripples;
+// screen space wake heights
+uniform sampler2D wakes;
+// depth of the fish covering each pixel in red, 0 at the surface
+uniform sampler2D fish_depth;
+uniform mat4 inv_view_proj;
+// world space size of the ripple field, centered on the origin
+uniform vec2 ripple_size;
 uniform float time;
+// points from the surface towards the sun, z is out of the water
+uniform vec3 sun_direction;
+uniform vec3 sun_color;
+uniform float sun_shininess;
+// screen uv offset per unit of surface slope
+uniform float refraction;
+uniform vec3 depth_tint;
+// share of depth_tint a fish on the floor takes on
+uniform float depth_attenuation;
+// blur radius in pixels for a fish on the floor
+uniform float depth_blur;
 
 in vec2 uv;
 out vec4 c;
 
-vec2 random2(vec2 st){
-    st = vec2( dot(st,vec2(127.1,311.7)),
-              dot(st,vec2(269.5,183.3)) );
-    return -1.0 + 2.0*fract(sin(st)*43758.5453123);
+// slope of the ripple height field at `world`, in height per world unit
+vec2 ripple_gradient(vec2 world) {
+	vec2 ruv = world / ripple_size + 0.5;
+	vec2 texel = 1.0 / vec2(textureSize(ripples, 0));
+	float l = texture(ripples, ruv - vec2(texel.x, 0.0)).r;
+	float r = texture(ripples, ruv + vec2(texel.x, 0.0)).r;
+	float d = texture(ripples, ruv - vec2(0.0, texel.y)).r;
+	float u = texture(ripples, ruv + vec2(0.0, texel.y)).r;
+	return vec2(r - l, u - d) / (2.0 * texel * ripple_size);
 }
 
-// https://thebookofshaders.com/edit.php#11/2d-gnoise.frag
-float perlin(vec2 st) {
-    vec2 i = floor(st);
-    vec2 f = fract(st);
-    vec2 u = f*f*(3.0-2.0*f);
-    return mix( mix( dot( random2(i + vec2(0.0,0.0) ), f - vec2(0.0,0.0) ),
-                     dot( random2(i + vec2(1.0,0.0) ), f - vec2(1.0,0.0) ), u.x),
-                mix( dot( random2(i + vec2(0.0,1.0) ), f - vec2(0.0,1.0) ),
-                     dot( random2(i + vec2(1.0,1.0) ), f - vec2(1.0,1.0) ), u.x), u.y);
+// slope of the wakes at `uv`, in height per world unit like ripple_gradient
+vec2 wake_gradient(vec2 uv) {
+	vec2 texel = 1.0 / vec2(textureSize(wakes, 0));
+	float l = texture(wakes, uv - vec2(texel.x, 0.0)).r;
+	float r = texture(wakes, uv + vec2(texel.x, 0.0)).r;
+	float d = texture(wakes, uv - vec2(0.0, texel.y)).r;
+	float u = texture(wakes, uv + vec2(0.0, texel.y)).r;
+	vec2 per_uv = vec2(r - l, u - d) / (2.0 * texel);
+	// the texture follows the screen, so undo the camera's zoom and rotation
+	mat2 world_per_uv = mat2(inv_view_proj) * 2.0;
+	return inverse(transpose(world_per_uv)) * per_uv;
 }
 
-float multisampled_perlin(vec2 offset) {
-	vec2 st = uv + offset;
-	float height = perlin(st * 10.0) * 0.5;
-	height += perlin(st * 20.0) * 0.3;
-	height += perlin(st * 30.0) * 0.2;
-	return height;
-}
-
-// use perlin to generate dudv and normal map
 void main() {
-	// float specular_strength = 0.75;
-	// vec3 light_color = vec3(1.0);
-	// vec3 color = vec3(1.0, 0.5, 0.2);
-	//
-	// vec3 frag_normal = vec3(
-	// 			multisampled_perlin(vec2(-1, -1) * time * 0.015),
-	// 			multisampled_perlin(vec2(1, -1) * time * 0.01),
-	// 			multisampled_perlin(vec2(-1, 1) * time * 0.012)
-	// 		);
-	//
-	// vec3 camera_position = vec3(0, 0, -1);
-	// vec3 light_source = vec3(-1, -1, -3);
-	// vec3 frag_position = vec3(uv, 0);
-	//
-	// vec3 norm = normalize(frag_normal);
-	// vec3 light_dir = normalize(light_source - frag_position);  
-	//
-	// vec3 view_dir = normalize(camera_position - frag_position);
-	// vec3 reflect_dir = reflect(-light_dir, norm); 
-	// float spec = pow(max(dot(view_dir, reflect_dir), 0.0), 32.0);
-	// vec3 specular = specular_strength * spec * light_color;  
-
 	vec2 uv_offset = texture(dudv, time * 0.09 + uv).rg;
 	uv_offset = (uv_offset - 0.5) * 2.0;
 
-	float s1 = multisampled_perlin(vec2(-1, 1) * time * 0.1 + uv_offset * 0.002) * 0.5 + 0.25;
-	float s2 = multisampled_perlin(vec2(-1, -1) * time * 0.15) * 0.5 + 0.25;
-	float sample = s1 + s2;
-	// float s3 = multisampled_perlin(vec2(-1, 0) * time * 0.08 + uv_offset * 0.01) * 0.5 + 0.15;
-	// float sample = s1 + s2 + s3;
+	vec2 world = (inv_view_proj * vec4(uv * 2.0 - 1.0, 0.0, 1.0)).xy;
+	vec2 gradient = ripple_gradient(world) + wake_gradient(uv);
+	vec3 normal = normalize(vec3(-(surface_slope(world, time) + gradient), 1.0));
 
-	float cutoff = 0.5;
-	float sl = step(cutoff, sample);
-	float l = smoothstep(cutoff, 1.0, sample);
-	vec3 specular = vec3(l, l, l);
+	// Blinn-Phong, the camera looks straight down
+	vec3 view_dir = vec3(0.0, 0.0, 1.0);
+	vec3 halfway = normalize(normalize(sun_direction) + view_dir);
+	float spec = pow(max(dot(normal, halfway), 0.0), sun_shininess);
+	vec3 specular = spec * sun_color;
 
-	vec3 col = vec3(texture(framebuffer, uv + uv_offset * 0.001));
+	// deeper fish are seen through more water: bent further, blurrier and
+	// tinted towards the color of the water
+	float depth = texture(fish_depth, uv).r;
+	vec2 bend = gradient * refraction * (1.0 + 2.0 * depth);
+	vec2 sample_uv = uv + uv_offset * 0.001 * (1.0 + 4.0 * depth) + bend;
+	vec2 blur = depth * depth_blur / vec2(textureSize(framebuffer, 0));
+	vec3 col = texture(framebuffer, sample_uv).rgb * 0.4;
+	col += texture(framebuffer, sample_uv + vec2(blur.x, 0.0)).rgb * 0.15;
+	col += texture(framebuffer, sample_uv - vec2(blur.x, 0.0)).rgb * 0.15;
+	col += texture(framebuffer, sample_uv + vec2(0.0, blur.y)).rgb * 0.15;
+	col += texture(framebuffer, sample_uv - vec2(0.0, blur.y)).rgb * 0.15;
+	col = mix(col, depth_tint, depth * depth_attenuation);
 	c = vec4(col + specular, 1.0);
-}  
+}
@@ -0,0 +1,112 @@
+use crate::{
+    joint::JointRenderer,
+    postprocess,
+    shader::uniform,
+    spine::Spine,
+    target::{RenderTarget, TargetFormat},
+};
+use glam::{Mat4, Vec2};
+use glow::HasContext;
+
+/// Texels between blur taps, wider is softer.
+const BLUR_SPREAD: f32 = 2.0;
+/// Darkness of a fully covered patch of floor.
+const STRENGTH: f32 = 0.45;
+
+/// Soft fish shadows on the pond floor.
+///
+/// The fish are drawn as silhouettes into a mask, blurred in two separable
+/// passes, then darken whatever is in the scene below the fish.
+pub struct ShadowRenderer {
+    mask: RenderTarget,
+    scratch: RenderTarget,
+    blur_shader: glow::Program,
+    composite_shader: glow::Program,
+    vao: glow::VertexArray,
+    _vbo: glow::Buffer,
+    size: Vec2,
+}
+
+impl ShadowRenderer {
+    pub fn new(gl: &glow::Context, width: usize, height: usize) -> Self {
+        let (vao, vbo) = postprocess::fullscreen_quad(gl);
+        Self {
+            mask: RenderTarget::new(gl, width, height, TargetFormat::Rgba8),
+            scratch: RenderTarget::new(gl, width, height, TargetFormat::Rgba8),
+            blur_shader: crate::compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/blur.frag"
+            ),
+            composite_shader: crate::compile_shader!(
+                gl,
+                "shaders/postprocess.vert",
+                "shaders/shadow.frag"
+            ),
+            vao,
+            _vbo: vbo,
+            size: Vec2::new(width as f32, height as f32),
+        }
+    }
+
+    pub fn resize(&mut self, gl: &glow::Context, width: usize, height: usize) {
+        self.mask.resize(gl, width, height);
+        self.scratch.resize(gl, width, height);
+        self.size = Vec2::new(width as f32, height as f32);
+    }
+
+    /// Draws the blurred shadow mask, fish `i` displaced by `offset(i)` in
+    /// world units. Leaves the mask framebuffer bound.
+    pub fn render(
+        &self,
+        gl: &glow::Context,
+        joint_renderer: &mut JointRenderer,
+        spines: &[Spine],
+        view_proj: Mat4,
+        offset: impl Fn(usize) -> Vec2,
+    ) {
+        unsafe {
+            self.mask.bind(gl);
+            gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+            joint_renderer.render_silhouettes(gl, spines, view_proj, offset);
+
+            gl.disable(glow::DEPTH_TEST);
+            gl.disable(glow::BLEND);
+            gl.use_program(Some(self.blur_shader));
+            gl.bind_vertex_array(Some(self.vao));
+            let texel = 1.0 / self.size * BLUR_SPREAD;
+            for (source, destination, direction) in [
+                (&self.mask, &self.scratch, Vec2::new(texel.x, 0.0)),
+                (&self.scratch, &self.mask, Vec2::new(0.0, texel.y)),
+            ] {
+                destination.bind(gl);
+                gl.bind_texture(glow::TEXTURE_2D, Some(source.texture));
+                uniform(gl, self.blur_shader, "direction", |location| {
+                    gl.uniform_2_f32(location, direction.x, direction.y);
+                });
+                gl.draw_arrays(glow::TRIANGLES, 0, 6);
+            }
+            gl.bind_vertex_array(None);
+            gl.enable(glow::BLEND);
+            gl.enable(glow::DEPTH_TEST);
+        }
+    }
+
+    /// Darkens the bound framebuffer with the mask from
+    /// [`ShadowRenderer::render`].
+    pub fn composite(&self, gl: &glow::Context) {
+        unsafe {
+            gl.disable(glow::DEPTH_TEST);
+            gl.use_program(Some(self.composite_shader));
+            uniform(gl, self.composite_shader, "strength", |location| {
+                gl.uniform_1_f32(location, STRENGTH);
+            });
+            gl.bind_vertex_array(Some(self.vao));
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.mask.texture));
+            gl.draw_arrays(glow::TRIANGLES, 0, 6);
+            gl.bind_vertex_array(None);
+            gl.enable(glow::DEPTH_TEST);
+        }
+    }
+}
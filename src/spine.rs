@@ -0,0 +1,84 @@
+//! Chain of joints dragged along by a boid, see
+//! https://github.com/argonautcode/animal-proc-anim
+
+use glam::Vec2;
+
+pub const SEGMENTS: usize = 14;
+pub const JOINT_SIZES: [f32; SEGMENTS] = [
+    10.0, 18.0, 25.0, 23.0, 24.0, 23.0, 22.0, 21.0, 16.0, 14.0, 10.0, 6.0, 3.0, 2.0,
+];
+
+const SPEED: f32 = 350.0;
+const SEPARATION: f32 = 20.0;
+const MIN_JOINT_ANGLE: f32 = std::f32::consts::PI / 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Joint {
+    pub size: f32,
+    pub translation: Vec2,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spine {
+    pub joints: [Joint; SEGMENTS],
+}
+
+impl Spine {
+    /// Lays the spine out straight behind `head`, trailing away from `heading`.
+    pub fn new(head: Vec2, heading: Vec2) -> Self {
+        let back = -heading.normalize_or(Vec2::X);
+        let mut offset = 0.0;
+        Self {
+            joints: JOINT_SIZES.map(|size| {
+                let translation = head + back * offset;
+                offset += SEPARATION;
+                Joint { size, translation }
+            }),
+        }
+    }
+
+    pub fn head(&self) -> Vec2 {
+        self.joints[0].translation
+    }
+
+    pub fn heading(&self) -> Vec2 {
+        (self.joints[0].translation - self.joints[1].translation).normalize_or(Vec2::X)
+    }
+
+    /// Drags the chain towards a boid at `head` moving along `velocity`.
+    pub fn follow(&mut self, head: Vec2, velocity: Vec2, dt: f32) {
+        let joints = &mut self.joints;
+        let mut target = head + velocity.normalize_or(Vec2::X) * SEPARATION + SPEED * dt;
+
+        for i in 0..joints.len() {
+            let joint = &mut joints[i];
+            let offset = target - joint.translation;
+            let current_distance = offset.length();
+
+            if current_distance > 0.0 {
+                let constrained_position = target - offset.normalize() * SEPARATION;
+                joint.translation = constrained_position;
+            }
+
+            target = joint.translation;
+            if i < joints.len() - 2 {
+                let joint_translation = joints[i].translation;
+                let anchor_translation = joints[i + 1].translation;
+                let joint2_translation = joints[i + 2].translation;
+
+                let normalized_joint =
+                    (joint_translation - anchor_translation).normalize_or(Vec2::X);
+                let normalized_joint2 =
+                    (joint2_translation - anchor_translation).normalize_or(Vec2::X);
+                let angle = normalized_joint.angle_to(normalized_joint2);
+
+                if angle.abs() < MIN_JOINT_ANGLE {
+                    let rotation = MIN_JOINT_ANGLE * angle.signum();
+                    let constrained_direction = normalized_joint.rotate(Vec2::from_angle(rotation));
+                    let joint2 = &mut joints[i + 2];
+                    joint2.translation = constrained_direction * SEPARATION + anchor_translation;
+                }
+            }
+        }
+    }
+}
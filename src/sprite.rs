@@ -1,61 +1,63 @@
-use crate::{
-    shader::uniform,
-    texture::{self, Image},
-};
-use glam::{Mat4, Quat, Vec2, Vec3};
+use crate::{atlas::AtlasRegion, shader::uniform};
+use glam::{Mat4, Quat, Vec2, Vec3, Vec4};
 use glazer::glow::{self, HasContext};
 
 #[derive(Clone, Copy)]
 pub struct Sprite {
+    /// `z` is the layer, sprites on higher layers are drawn on top.
     pub translation: Vec3,
     pub rotation: Quat,
     pub scale: Vec2,
-    pub image: Image,
+    pub color: Vec4,
+    pub image: AtlasRegion,
 }
 
 impl Sprite {
-    pub fn from_image(gl: &glow::Context, path: &str) -> Self {
+    pub fn new(image: AtlasRegion) -> Self {
         Self {
             translation: Vec3::ZERO,
             rotation: Quat::default(),
             scale: Vec2::ONE,
-            image: texture::load_image(gl, path),
+            color: Vec4::ONE,
+            image,
         }
     }
 
-    pub fn from_size(gl: &glow::Context, size: Vec2) -> Self {
+    /// An untextured sprite `size` world units large, `white` should be
+    /// [`crate::atlas::Atlas::white`].
+    pub fn from_size(white: AtlasRegion, size: Vec2) -> Self {
         Self {
-            translation: Vec3::ZERO,
-            rotation: Quat::default(),
             scale: size,
-            image: texture::default_image(gl),
+            ..Self::new(white)
         }
     }
 }
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct SpriteVertex {
+    position: [f32; 3],
+    uv: [f32; 2],
+    color: [f32; 4],
+}
+
+/// Collects sprites over a frame and draws them in as few calls as possible.
+///
+/// Sprites are sorted by layer, then by atlas, and every run that shares
+/// both is a single draw.
 pub struct SpriteRenderer {
     shader: glow::Program,
     vao: glow::VertexArray,
-    _vbo: glow::Buffer,
-    _ebo: glow::Buffer,
+    vbo: glow::Buffer,
+    vbo_len: usize,
+    ebo: glow::Buffer,
+    ebo_len: usize,
+    sprites: Vec<Sprite>,
+    vertices: Vec<SpriteVertex>,
 }
 
 impl SpriteRenderer {
-    pub fn new(gl: &glow::Context, width: usize, height: usize) -> Self {
-        #[rustfmt::skip]
-        let vertices = [
-             // positions       texture coords
-             0.5,  0.5, 0.0,    1.0, 1.0, // top right
-             0.5, -0.5, 0.0,    1.0, 0.0, // bottom right
-            -0.5, -0.5, 0.0,    0.0, 0.0, // bottom let
-            -0.5,  0.5, 0.0,    0.0, 1f32 // top let 
-        ];
-        #[rustfmt::skip]
-        let indices = [
-            0, 1, 3,
-            1, 2, 3u32,
-        ];
-
+    pub fn new(gl: &glow::Context) -> Self {
         unsafe {
             let vao = gl.create_vertex_array().unwrap();
             let vbo = gl.create_buffer().unwrap();
@@ -63,72 +65,148 @@ impl SpriteRenderer {
 
             gl.bind_vertex_array(Some(vao));
             gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
-            let data =
-                core::slice::from_raw_parts(vertices.as_ptr() as *const u8, vertices.len() * 4);
-            gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, data, glow::STATIC_DRAW);
-
             gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, Some(ebo));
-            let data =
-                core::slice::from_raw_parts(indices.as_ptr() as *const u8, indices.len() * 4);
-            gl.buffer_data_u8_slice(glow::ELEMENT_ARRAY_BUFFER, data, glow::STATIC_DRAW);
 
-            let stride = 5 * 4;
+            let stride = core::mem::size_of::<SpriteVertex>() as i32;
             gl.vertex_attrib_pointer_f32(0, 3, glow::FLOAT, false, stride, 0);
             gl.enable_vertex_attrib_array(0);
             gl.vertex_attrib_pointer_f32(1, 2, glow::FLOAT, false, stride, 3 * 4);
             gl.enable_vertex_attrib_array(1);
+            gl.vertex_attrib_pointer_f32(2, 4, glow::FLOAT, false, stride, 5 * 4);
+            gl.enable_vertex_attrib_array(2);
 
             gl.bind_vertex_array(None);
             gl.bind_buffer(glow::ARRAY_BUFFER, None);
+            gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, None);
 
             let shader = crate::compile_shader!(gl, "shaders/sprite.vert", "shaders/sprite.frag");
-            gl.use_program(Some(shader));
-
-            uniform(gl, shader, "proj_matrix", |location| {
-                let w_2 = width as f32 / 2.0;
-                let h_2 = height as f32 / 2.0;
-                let proj_matrix = Mat4::orthographic_rh_gl(-w_2, w_2, -h_2, h_2, -1000.0, 1000.0);
-                gl.uniform_matrix_4_f32_slice(location, false, &proj_matrix.to_cols_array());
-            });
 
             Self {
                 shader,
                 vao,
-                _vbo: vbo,
-                _ebo: ebo,
+                vbo,
+                vbo_len: 0,
+                ebo,
+                ebo_len: 0,
+                sprites: Vec::new(),
+                vertices: Vec::new(),
             }
         }
     }
 
-    pub fn resize(&self, gl: &glow::Context, width: usize, height: usize) {
+    /// Queues `sprite` for the next [`SpriteRenderer::flush`].
+    pub fn push(&mut self, sprite: Sprite) {
+        self.sprites.push(sprite);
+    }
+
+    /// Draws every queued sprite and returns the number of draw calls issued.
+    pub fn flush(&mut self, gl: &glow::Context, view_proj: Mat4) -> usize {
+        if self.sprites.is_empty() {
+            return 0;
+        }
+
+        // sprites on the same layer are grouped by texture, the sort is stable
+        // so they keep their submission order only within the same texture
+        self.sprites.sort_by(|a, b| {
+            a.translation
+                .z
+                .total_cmp(&b.translation.z)
+                .then(a.image.texture.cmp(&b.image.texture))
+        });
+
+        self.vertices.clear();
+        for sprite in self.sprites.iter() {
+            let model_matrix = Mat4::from_scale_rotation_translation(
+                (sprite.scale * sprite.image.size).extend(1.0),
+                sprite.rotation,
+                sprite.translation,
+            );
+            let min = sprite.image.uv_min;
+            let max = sprite.image.uv_max;
+            for (corner, uv) in [
+                (Vec2::new(0.5, 0.5), max),
+                (Vec2::new(0.5, -0.5), Vec2::new(max.x, min.y)),
+                (Vec2::new(-0.5, -0.5), min),
+                (Vec2::new(-0.5, 0.5), Vec2::new(min.x, max.y)),
+            ] {
+                self.vertices.push(SpriteVertex {
+                    position: model_matrix.transform_point3(corner.extend(0.0)).into(),
+                    uv: uv.into(),
+                    color: sprite.color.into(),
+                });
+            }
+        }
+
+        let mut draw_calls = 0;
         unsafe {
             gl.use_program(Some(self.shader));
             uniform(gl, self.shader, "proj_matrix", |location| {
-                let w_2 = width as f32 / 2.0;
-                let h_2 = height as f32 / 2.0;
-                let proj_matrix = Mat4::orthographic_rh_gl(-w_2, w_2, -h_2, h_2, -1000.0, 1000.0);
-                gl.uniform_matrix_4_f32_slice(location, false, &proj_matrix.to_cols_array());
+                gl.uniform_matrix_4_f32_slice(location, false, &view_proj.to_cols_array());
             });
+            gl.bind_vertex_array(Some(self.vao));
+            self.upload(gl);
+
+            let mut start = 0;
+            while start < self.sprites.len() {
+                let first = &self.sprites[start];
+                let end = start
+                    + self.sprites[start..]
+                        .iter()
+                        .take_while(|s| {
+                            s.translation.z == first.translation.z
+                                && s.image.texture == first.image.texture
+                        })
+                        .count();
+
+                gl.bind_texture(glow::TEXTURE_2D, Some(first.image.texture));
+                gl.draw_elements(
+                    glow::TRIANGLES,
+                    ((end - start) * 6) as i32,
+                    glow::UNSIGNED_INT,
+                    (start * 6 * 4) as i32,
+                );
+                draw_calls += 1;
+                start = end;
+            }
+
+            gl.bind_vertex_array(None);
         }
+
+        self.sprites.clear();
+        draw_calls
     }
 
-    #[allow(unused)]
-    pub fn render(&self, gl: &glow::Context, sprite: &Sprite) {
+    /// Uploads the vertices and makes sure there are indices for all of them,
+    /// the vao must be bound.
+    unsafe fn upload(&mut self, gl: &glow::Context) {
         unsafe {
-            gl.use_program(Some(self.shader));
-            gl.bind_texture(glow::TEXTURE_2D, Some(sprite.image.texture));
-
-            uniform(gl, self.shader, "model_matrix", |location| {
-                let model_matrix = Mat4::from_scale_rotation_translation(
-                    (sprite.scale * Vec2::new(sprite.image.width, sprite.image.height)).extend(1.0),
-                    sprite.rotation,
-                    sprite.translation,
-                );
-                gl.uniform_matrix_4_f32_slice(location, false, &model_matrix.to_cols_array());
-            });
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vbo));
+            let data = core::slice::from_raw_parts(
+                self.vertices.as_ptr() as *const u8,
+                core::mem::size_of_val(self.vertices.as_slice()),
+            );
+            if self.vbo_len < self.vertices.len() {
+                self.vbo_len = self.vertices.len();
+                gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, data, glow::DYNAMIC_DRAW);
+            } else {
+                gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, data);
+            }
 
-            gl.bind_vertex_array(Some(self.vao));
-            gl.draw_elements(glow::TRIANGLES, 6, glow::UNSIGNED_INT, 0);
+            // the quad indices never change, only grow
+            let quads = self.sprites.len();
+            if self.ebo_len < quads {
+                self.ebo_len = quads.next_power_of_two();
+                let indices = (0..self.ebo_len as u32)
+                    .flat_map(|quad| {
+                        let i = quad * 4;
+                        [i, i + 1, i + 3, i + 1, i + 2, i + 3]
+                    })
+                    .collect::<Vec<_>>();
+                let data =
+                    core::slice::from_raw_parts(indices.as_ptr() as *const u8, indices.len() * 4);
+                gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, Some(self.ebo));
+                gl.buffer_data_u8_slice(glow::ELEMENT_ARRAY_BUFFER, data, glow::STATIC_DRAW);
+            }
         }
     }
 }
@@ -0,0 +1,111 @@
+use glow::HasContext;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetFormat {
+    Rgba8,
+    /// Half float color that can hold values above 1, falls back to
+    /// [`TargetFormat::Rgba8`] where float targets can not be rendered to.
+    Rgba16F,
+}
+
+impl TargetFormat {
+    /// `(internal format, format, type)` for `tex_image_2d`.
+    pub fn gl(self, gl: &glow::Context) -> (u32, u32, u32) {
+        match self {
+            Self::Rgba16F if float_targets(gl) => (glow::RGBA16F, glow::RGBA, glow::HALF_FLOAT),
+            _ => (glow::RGBA8, glow::RGBA, glow::UNSIGNED_BYTE),
+        }
+    }
+}
+
+/// Desktop GL 3.3 always renders to float textures, WebGL2 needs an
+/// extension.
+fn float_targets(gl: &glow::Context) -> bool {
+    !cfg!(target_arch = "wasm32") || gl.supported_extensions().contains("EXT_color_buffer_float")
+}
+
+/// An offscreen color texture that can be drawn into and then sampled.
+pub struct RenderTarget {
+    pub framebuffer: glow::Framebuffer,
+    pub texture: glow::Texture,
+    format: TargetFormat,
+}
+
+impl RenderTarget {
+    pub fn new(gl: &glow::Context, width: usize, height: usize, format: TargetFormat) -> Self {
+        unsafe {
+            let target = Self {
+                framebuffer: gl.create_framebuffer().unwrap(),
+                texture: gl.create_texture().unwrap(),
+                format,
+            };
+            target.resize(gl, width, height);
+            target
+        }
+    }
+
+    pub fn resize(&self, gl: &glow::Context, width: usize, height: usize) {
+        unsafe {
+            let (internal, format, ty) = self.format.gl(gl);
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                internal as i32,
+                width as i32,
+                height as i32,
+                0,
+                format,
+                ty,
+                glow::PixelUnpackData::Slice(None),
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.bind_texture(glow::TEXTURE_2D, None);
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.framebuffer));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(self.texture),
+                0,
+            );
+            if gl.check_framebuffer_status(glow::FRAMEBUFFER) != glow::FRAMEBUFFER_COMPLETE {
+                panic!("render target framebuffer is not complete");
+            }
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+        }
+    }
+
+    pub fn bind(&self, gl: &glow::Context) {
+        unsafe {
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.framebuffer));
+        }
+    }
+
+    pub fn delete(self, gl: &glow::Context) {
+        unsafe {
+            gl.delete_framebuffer(self.framebuffer);
+            gl.delete_texture(self.texture);
+        }
+    }
+}
@@ -1,36 +1,6 @@
 use glow::HasContext;
 use image::EncodableLayout;
 
-#[derive(Clone, Copy)]
-pub struct Image {
-    pub texture: glow::Texture,
-    pub width: f32,
-    pub height: f32,
-}
-
-pub fn default_image(gl: &glow::Context) -> Image {
-    Image {
-        texture: load_texture_inner(gl, &[255; 3], 1, 1),
-        width: 1.0,
-        height: 1.0,
-    }
-}
-
-pub fn load_image(gl: &glow::Context, path: &str) -> Image {
-    let image = image::open(path).unwrap();
-    let width = image.width();
-    let height = image.height();
-    let rgb = image.flipv().to_rgb8();
-    let bytes = rgb.as_bytes();
-    let texture = load_texture_inner(gl, bytes, width, height);
-
-    Image {
-        texture,
-        width: width as f32,
-        height: height as f32,
-    }
-}
-
 pub fn load_texture(gl: &glow::Context, path: &str) -> glow::Texture {
     let image = image::open(path).unwrap();
     let width = image.width();
@@ -0,0 +1,199 @@
+use crate::{
+    pond::PondState,
+    shader::uniform,
+    spine::Spine,
+    target::{RenderTarget, TargetFormat},
+};
+use glam::{Mat4, Vec2};
+use glow::HasContext;
+use std::collections::VecDeque;
+
+/// Distance a fish travels between two trail points.
+const SPACING: f32 = 8.0;
+/// Seconds before a trail point has faded out.
+const LIFETIME: f32 = 1.5;
+/// Sideways spread of the arms relative to the fish speed, roughly the
+/// 19.5 degree half angle of a Kelvin wake.
+const SPREAD: f32 = 0.35;
+/// Speed at which a wake is at full strength.
+const FULL_SPEED: f32 = 150.0;
+
+#[derive(Clone, Copy)]
+struct TrailPoint {
+    translation: Vec2,
+    heading: Vec2,
+    speed: f32,
+    age: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct WakeVertex {
+    position: [f32; 2],
+    intensity: f32,
+    /// -1 on the inner edge of an arm, 1 on the outer edge.
+    across: f32,
+}
+
+/// Draws a V shaped wake behind every fish into an offscreen height texture,
+/// which the water pass turns into distortion.
+pub struct WakeRenderer {
+    shader: glow::Program,
+    vao: glow::VertexArray,
+    vbo: glow::Buffer,
+    vbo_len: usize,
+    target: RenderTarget,
+    trails: Vec<VecDeque<TrailPoint>>,
+    vertices: Vec<WakeVertex>,
+}
+
+impl WakeRenderer {
+    pub fn new(gl: &glow::Context, width: usize, height: usize) -> Self {
+        unsafe {
+            let shader = crate::compile_shader!(gl, "shaders/wake.vert", "shaders/wake.frag");
+
+            let vao = gl.create_vertex_array().unwrap();
+            let vbo = gl.create_buffer().unwrap();
+            gl.bind_vertex_array(Some(vao));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
+            let stride = core::mem::size_of::<WakeVertex>() as i32;
+            gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, stride, 0);
+            gl.enable_vertex_attrib_array(0);
+            gl.vertex_attrib_pointer_f32(1, 1, glow::FLOAT, false, stride, 2 * 4);
+            gl.enable_vertex_attrib_array(1);
+            gl.vertex_attrib_pointer_f32(2, 1, glow::FLOAT, false, stride, 3 * 4);
+            gl.enable_vertex_attrib_array(2);
+            gl.bind_vertex_array(None);
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+            Self {
+                shader,
+                vao,
+                vbo,
+                vbo_len: 0,
+                target: RenderTarget::new(gl, width, height, TargetFormat::Rgba8),
+                trails: Vec::new(),
+                vertices: Vec::new(),
+            }
+        }
+    }
+
+    pub fn resize(&self, gl: &glow::Context, width: usize, height: usize) {
+        self.target.resize(gl, width, height);
+    }
+
+    /// Screen sized wake heights in the red channel, valid after
+    /// [`WakeRenderer::render`].
+    pub fn texture(&self) -> glow::Texture {
+        self.target.texture
+    }
+
+    /// Extends the trails with `state` and redraws them into
+    /// [`WakeRenderer::texture`], leaves the wake framebuffer bound.
+    pub fn render(&mut self, gl: &glow::Context, state: &PondState, view_proj: Mat4, dt: f32) {
+        self.update_trails(state, dt);
+
+        self.vertices.clear();
+        for (trail, spine) in self.trails.iter().zip(state.spines().iter()) {
+            push_trail(&mut self.vertices, trail, fish_size(spine));
+        }
+
+        unsafe {
+            self.target.bind(gl);
+            gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+            if self.vertices.is_empty() {
+                return;
+            }
+
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vbo));
+            let data = core::slice::from_raw_parts(
+                self.vertices.as_ptr() as *const u8,
+                core::mem::size_of_val(self.vertices.as_slice()),
+            );
+            if self.vbo_len < self.vertices.len() {
+                self.vbo_len = self.vertices.len();
+                gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, data, glow::DYNAMIC_DRAW);
+            } else {
+                gl.buffer_sub_data_u8_slice(glow::ARRAY_BUFFER, 0, data);
+            }
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+            gl.use_program(Some(self.shader));
+            uniform(gl, self.shader, "proj_matrix", |location| {
+                gl.uniform_matrix_4_f32_slice(location, false, &view_proj.to_cols_array());
+            });
+
+            // overlapping wakes add up
+            gl.disable(glow::DEPTH_TEST);
+            gl.enable(glow::BLEND);
+            gl.blend_func(glow::ONE, glow::ONE);
+            gl.bind_vertex_array(Some(self.vao));
+            gl.draw_arrays(glow::TRIANGLES, 0, self.vertices.len() as i32);
+            gl.bind_vertex_array(None);
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+        }
+    }
+
+    fn update_trails(&mut self, state: &PondState, dt: f32) {
+        let fish = state.spines().iter().zip(state.boids().boids().iter());
+        self.trails.resize_with(state.spines().len(), VecDeque::new);
+
+        for (trail, (spine, boid)) in self.trails.iter_mut().zip(fish) {
+            for point in trail.iter_mut() {
+                point.age += dt;
+            }
+            while trail.back().is_some_and(|point| point.age > LIFETIME) {
+                trail.pop_back();
+            }
+
+            let head = spine.head();
+            if trail
+                .front()
+                .is_none_or(|point| point.translation.distance(head) >= SPACING)
+            {
+                trail.push_front(TrailPoint {
+                    translation: head,
+                    heading: spine.heading(),
+                    // a deep fish barely moves the surface
+                    speed: boid.velocity.length() * (1.0 - boid.depth),
+                    age: 0.0,
+                });
+            }
+        }
+    }
+}
+
+fn fish_size(spine: &Spine) -> f32 {
+    spine
+        .joints
+        .iter()
+        .map(|joint| joint.size)
+        .fold(0.0, f32::max)
+}
+
+/// Appends both arms of the wake, each a ribbon that drifts outwards from
+/// the path as it ages.
+fn push_trail(vertices: &mut Vec<WakeVertex>, trail: &VecDeque<TrailPoint>, size: f32) {
+    let width = size * 0.4;
+    for side in [1.0, -1.0] {
+        let arm = |point: &TrailPoint| {
+            let normal = point.heading.perp() * side;
+            let center = point.translation + normal * (size + point.age * point.speed * SPREAD);
+            let fade = 1.0 - point.age / LIFETIME;
+            let intensity = (point.speed / FULL_SPEED).min(1.0) * fade * size / 25.0;
+            let vertex = |offset: f32, across: f32| WakeVertex {
+                position: (center + normal * offset).into(),
+                intensity,
+                across,
+            };
+            (vertex(-width, -1.0), vertex(width, 1.0))
+        };
+
+        for (a, b) in trail.iter().zip(trail.iter().skip(1)) {
+            let (a_inner, a_outer) = arm(a);
+            let (b_inner, b_outer) = arm(b);
+            vertices.extend_from_slice(&[a_inner, a_outer, b_outer, a_inner, b_outer, b_inner]);
+        }
+    }
+}
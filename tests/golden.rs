@@ -0,0 +1,183 @@
+//! Renders seeded scenes on a software GL context and compares them against
+//! the reference images in `tests/golden`.
+//!
+//! `cargo test --features headless --test golden`
+//!
+//! Set `PROA_BLESS=1` to replace the references with the current output
+//! after an intended visual change. On a mismatch the rendered frame and a
+//! diff image are written next to the test binary's temporary files.
+
+use image::{Rgba, RgbaImage};
+use proa::headless::{self, HeadlessContext, HeadlessOptions};
+use std::path::{Path, PathBuf};
+
+/// Per pixel color difference still considered equal, from 0 to 1 in the
+/// perceptual YIQ metric, see [`color_delta`].
+const THRESHOLD: f32 = 0.1;
+/// Fraction of pixels allowed over [`THRESHOLD`], absorbs rasterization
+/// differences between Mesa versions along edges.
+const MAX_DIFFERENT: f32 = 0.002;
+
+const WIDTH: usize = 256;
+const HEIGHT: usize = 144;
+
+struct Scene {
+    name: &'static str,
+    options: HeadlessOptions,
+}
+
+fn scenes() -> Vec<Scene> {
+    let base = HeadlessOptions {
+        width: WIDTH,
+        height: HEIGHT,
+        frames: 30,
+        every: 30,
+        ..Default::default()
+    };
+    vec![
+        // the whole pipeline
+        Scene {
+            name: "pond",
+            options: base.clone(),
+        },
+        // fish, pebbles, caustics and shadows as drawn, before the post chain
+        Scene {
+            name: "pond_raw",
+            options: HeadlessOptions {
+                post_processing: false,
+                ..base.clone()
+            },
+        },
+        // a different school a few seconds in
+        Scene {
+            name: "seed_7",
+            options: HeadlessOptions {
+                seed: 7,
+                frames: 180,
+                every: 180,
+                ..base
+            },
+        },
+    ]
+}
+
+#[test]
+fn golden_images() {
+    let context = HeadlessContext::new().expect("failed to create a headless GL context");
+    let bless = std::env::var_os("PROA_BLESS").is_some();
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    std::fs::create_dir_all(&out).unwrap();
+
+    let mut failures = Vec::new();
+    for scene in scenes() {
+        let (_, image) = headless::render_frames(context.gl(), &scene.options)
+            .pop()
+            .unwrap();
+        let reference_path = golden.join(format!("{}.png", scene.name));
+
+        if bless {
+            std::fs::create_dir_all(&golden).unwrap();
+            image.save(&reference_path).unwrap();
+            continue;
+        }
+
+        let reference = match image::open(&reference_path) {
+            Ok(reference) => reference.to_rgba8(),
+            Err(err) => {
+                failures.push(format!(
+                    "{}: no reference at {} ({err}), run with PROA_BLESS=1",
+                    scene.name,
+                    reference_path.display()
+                ));
+                continue;
+            }
+        };
+
+        if let Err(err) = compare(&image, &reference) {
+            let (actual, diff) = write_failure(&out, scene.name, &image, &reference);
+            failures.push(format!(
+                "{}: {err}\n  actual: {}\n  diff:   {}",
+                scene.name,
+                actual.display(),
+                diff.display()
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn compare(image: &RgbaImage, reference: &RgbaImage) -> Result<(), String> {
+    if image.dimensions() != reference.dimensions() {
+        return Err(format!(
+            "size {:?} does not match the reference {:?}",
+            image.dimensions(),
+            reference.dimensions()
+        ));
+    }
+    let different = image
+        .pixels()
+        .zip(reference.pixels())
+        .filter(|(a, b)| color_delta(**a, **b) > THRESHOLD)
+        .count();
+    let fraction = different as f32 / (image.width() * image.height()) as f32;
+    if fraction > MAX_DIFFERENT {
+        return Err(format!(
+            "{different} pixels ({:.2}%) differ, at most {:.2}% may",
+            fraction * 100.0,
+            MAX_DIFFERENT * 100.0
+        ));
+    }
+    Ok(())
+}
+
+/// Perceptual difference between two colors in `[0, 1]`, the weighted YIQ
+/// distance from Kotsarenko and Ramos' "Measuring perceived color difference
+/// using YIQ NTSC transmission color space in mobile applications".
+fn color_delta(a: Rgba<u8>, b: Rgba<u8>) -> f32 {
+    // largest possible weighted distance, between black and white
+    const MAX_DELTA: f32 = 35215.0;
+    let yiq = |Rgba([r, g, b, _]): Rgba<u8>| {
+        let (r, g, b) = (r as f32, g as f32, b as f32);
+        (
+            r * 0.29889 + g * 0.58662 + b * 0.11448,
+            r * 0.59598 - g * 0.27418 - b * 0.32180,
+            r * 0.21147 - g * 0.52262 + b * 0.31115,
+        )
+    };
+    let (y1, i1, q1) = yiq(a);
+    let (y2, i2, q2) = yiq(b);
+    let (y, i, q) = (y1 - y2, i1 - i2, q1 - q2);
+    ((0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q) / MAX_DELTA).sqrt()
+}
+
+/// Saves the rendered frame and a diff: the reference faded to grey with
+/// every differing pixel in red.
+fn write_failure(
+    out: &Path,
+    name: &str,
+    image: &RgbaImage,
+    reference: &RgbaImage,
+) -> (PathBuf, PathBuf) {
+    let actual_path = out.join(format!("{name}.actual.png"));
+    let diff_path = out.join(format!("{name}.diff.png"));
+    image.save(&actual_path).unwrap();
+
+    let diff = RgbaImage::from_fn(image.width(), image.height(), |x, y| {
+        let a = *image.get_pixel(x, y);
+        let Some(b) = reference.get_pixel_checked(x, y) else {
+            return Rgba([255, 0, 255, 255]);
+        };
+        if color_delta(a, *b) > THRESHOLD {
+            Rgba([255, 0, 0, 255])
+        } else {
+            let Rgba([r, g, b, _]) = *b;
+            let grey = (r as f32 * 0.3 + g as f32 * 0.59 + b as f32 * 0.11) as u8;
+            let faded = 255 - (255 - grey) / 4;
+            Rgba([faded, faded, faded, 255])
+        }
+    });
+    diff.save(&diff_path).unwrap();
+    (actual_path, diff_path)
+}